@@ -0,0 +1,366 @@
+use {Async, Future, Complete, Stream, Sender};
+use std::collections::VecDeque;
+use std::error::Error;
+use std::sync::{Arc, Mutex};
+use std::fmt;
+
+/// Returns a `BroadcastSender` along with a `Subscriber` that creates streams
+/// receiving every value sent after they subscribed.
+///
+/// Subscribers apply back-pressure to the sender: the future returned by
+/// `BroadcastSender::send` completes once every subscriber has accepted the
+/// value, so a slow subscriber slows down all of them.
+pub fn broadcast<V, E>() -> (BroadcastSender<V, E>, Subscriber<V, E>)
+        where V: Clone + Send + 'static,
+              E: Clone + Send + 'static {
+    new(None)
+}
+
+/// Returns a `BroadcastSender` along with a `Subscriber` that creates streams
+/// receiving every value sent after they subscribed.
+///
+/// Sending never waits on subscribers. Instead, each subscriber buffers up to
+/// `capacity` values; a subscriber that falls further behind is dropped and
+/// its stream fails with `Lagged`.
+pub fn broadcast_lagged<V, E>(capacity: usize) -> (BroadcastSender<V, E>, Subscriber<V, E>)
+        where V: Clone + Send + 'static,
+              E: Clone + From<Lagged> + Send + 'static {
+    new(Some((capacity, From::from)))
+}
+
+fn new<V, E>(lag: Option<(usize, fn(Lagged) -> E)>) -> (BroadcastSender<V, E>, Subscriber<V, E>)
+        where V: Clone + Send + 'static,
+              E: Clone + Send + 'static {
+
+    let inner = Arc::new(Mutex::new(Inner {
+        subs: Vec::new(),
+        next_id: 0,
+        state: State::Open,
+        waiters: Vec::new(),
+        lag: lag,
+    }));
+
+    (BroadcastSender { inner: inner.clone() }, Subscriber { inner: inner })
+}
+
+/*
+ *
+ * ===== BroadcastSender =====
+ *
+ */
+
+/// The sending half of a broadcast. Dropping it ends every subscriber stream
+/// once they have received the values sent so far.
+pub struct BroadcastSender<V: Clone + Send + 'static, E: Clone + Send + 'static> {
+    inner: Arc<Mutex<Inner<V, E>>>,
+}
+
+impl<V: Clone + Send + 'static, E: Clone + Send + 'static> BroadcastSender<V, E> {
+    /// Sends a value to every current subscriber. Returns a future that
+    /// completes once every subscriber has accepted the value.
+    ///
+    /// Values sent without waiting on the returned future are queued for
+    /// each subscriber that is not ready for them.
+    pub fn send(&self, val: V) -> Future<(), ()> {
+        let ids = {
+            let mut inner = self.inner.lock().unwrap();
+            let lag = inner.lag.map(|(capacity, _)| capacity);
+
+            for sub in inner.subs.iter_mut() {
+                if sub.lagged {
+                    continue;
+                }
+
+                sub.queue.push_back(val.clone());
+
+                if let Some(capacity) = lag {
+                    if sub.queue.len() > capacity {
+                        debug!("BroadcastSender::send; subscriber lagged; id={}", sub.id);
+                        sub.lagged = true;
+                        sub.queue.clear();
+                    }
+                }
+            }
+
+            inner.ids()
+        };
+
+        for id in ids {
+            poll_sub(&self.inner, id);
+        }
+
+        let mut inner = self.inner.lock().unwrap();
+
+        if inner.lag.is_some() || inner.is_drained() {
+            return Future::of(());
+        }
+
+        let (complete, ret) = Future::pair();
+        inner.waiters.push(complete);
+        ret
+    }
+
+    /// Fails every subscriber stream with the given error once they have
+    /// received the values sent so far.
+    pub fn fail(self, err: E) {
+        self.inner.lock().unwrap().state = State::Failed(err);
+    }
+
+    /// Returns a `Subscriber` for this broadcast.
+    pub fn subscriber(&self) -> Subscriber<V, E> {
+        Subscriber { inner: self.inner.clone() }
+    }
+}
+
+impl<V: Clone + Send + 'static, E: Clone + Send + 'static> Drop for BroadcastSender<V, E> {
+    fn drop(&mut self) {
+        let ids = {
+            let mut inner = self.inner.lock().unwrap();
+
+            if let State::Open = inner.state {
+                inner.state = State::Closed;
+            }
+
+            inner.ids()
+        };
+
+        for id in ids {
+            poll_sub(&self.inner, id);
+        }
+    }
+}
+
+impl<V: Clone + Send + 'static, E: Clone + Send + 'static> fmt::Debug for BroadcastSender<V, E> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "BroadcastSender {{ ... }}")
+    }
+}
+
+/*
+ *
+ * ===== Subscriber =====
+ *
+ */
+
+/// Creates streams that receive the values sent by the associated
+/// `BroadcastSender`.
+pub struct Subscriber<V: Clone + Send + 'static, E: Clone + Send + 'static> {
+    inner: Arc<Mutex<Inner<V, E>>>,
+}
+
+impl<V: Clone + Send + 'static, E: Clone + Send + 'static> Subscriber<V, E> {
+    /// Returns a new stream receiving every value sent from now on. Dropping
+    /// the stream unsubscribes it.
+    pub fn subscribe(&self) -> Stream<V, E> {
+        let (sender, stream) = Stream::pair();
+        let mut inner = self.inner.lock().unwrap();
+
+        match inner.state {
+            State::Open => {
+                let id = inner.next_id;
+                inner.next_id += 1;
+
+                inner.subs.push(Sub {
+                    id: id,
+                    queue: VecDeque::new(),
+                    sender: Some(sender),
+                    lagged: false,
+                });
+            }
+            State::Closed => {
+                drop(inner);
+                drop(sender);
+            }
+            State::Failed(ref e) => {
+                let e = e.clone();
+                drop(inner);
+                sender.fail(e);
+            }
+        }
+
+        stream
+    }
+}
+
+impl<V: Clone + Send + 'static, E: Clone + Send + 'static> Clone for Subscriber<V, E> {
+    fn clone(&self) -> Subscriber<V, E> {
+        Subscriber { inner: self.inner.clone() }
+    }
+}
+
+impl<V: Clone + Send + 'static, E: Clone + Send + 'static> fmt::Debug for Subscriber<V, E> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "Subscriber {{ ... }}")
+    }
+}
+
+/*
+ *
+ * ===== Internals =====
+ *
+ */
+
+struct Inner<V: Send + 'static, E: Send + 'static> {
+    subs: Vec<Sub<V, E>>,
+    next_id: u64,
+    state: State<E>,
+    // Pending sends waiting on every subscriber to accept their value
+    waiters: Vec<Complete<(), ()>>,
+    lag: Option<(usize, fn(Lagged) -> E)>,
+}
+
+enum State<E> {
+    Open,
+    Closed,
+    Failed(E),
+}
+
+struct Sub<V: Send + 'static, E: Send + 'static> {
+    id: u64,
+    queue: VecDeque<V>,
+    // The sender is only present when the subscriber is not in the middle of
+    // a send.
+    sender: Option<Sender<V, E>>,
+    lagged: bool,
+}
+
+enum Action<V: Send + 'static, E: Send + 'static> {
+    Send(Sender<V, E>, V),
+    Close(Sender<V, E>),
+    Fail(Sender<V, E>, E),
+}
+
+impl<V: Clone + Send + 'static, E: Clone + Send + 'static> Inner<V, E> {
+    fn ids(&self) -> Vec<u64> {
+        self.subs.iter().map(|sub| sub.id).collect()
+    }
+
+    fn is_drained(&self) -> bool {
+        self.subs.iter().all(|sub| sub.lagged || sub.queue.is_empty())
+    }
+
+    fn remove(&mut self, id: u64) {
+        self.subs.retain(|sub| sub.id != id);
+    }
+
+    fn next_action(&mut self, id: u64) -> Option<Action<V, E>> {
+        let idx = match self.subs.iter().position(|sub| sub.id == id) {
+            Some(idx) => idx,
+            None => return None,
+        };
+
+        let sender = match self.subs[idx].sender.take() {
+            Some(sender) => sender,
+            None => return None,
+        };
+
+        if !self.subs[idx].lagged {
+            if let Some(val) = self.subs[idx].queue.pop_front() {
+                return Some(Action::Send(sender, val));
+            }
+        }
+
+        let action = if self.subs[idx].lagged {
+            let (_, lagged) = self.lag.expect("lagged subscriber without capacity");
+            Action::Fail(sender, lagged(Lagged))
+        } else {
+            match self.state {
+                State::Open => {
+                    self.subs[idx].sender = Some(sender);
+                    return None;
+                }
+                State::Closed => Action::Close(sender),
+                State::Failed(ref e) => Action::Fail(sender, e.clone()),
+            }
+        };
+
+        self.subs.remove(idx);
+        Some(action)
+    }
+
+    fn take_waiters(&mut self) -> Vec<Complete<(), ()>> {
+        if self.is_drained() {
+            self.waiters.drain(..).collect()
+        } else {
+            vec![]
+        }
+    }
+}
+
+// Make progress on the subscriber if it is not in the middle of a send. The
+// lock is never held while interacting with the subscriber stream, since
+// that may invoke callbacks.
+fn poll_sub<V, E>(inner: &Arc<Mutex<Inner<V, E>>>, id: u64)
+        where V: Clone + Send + 'static,
+              E: Clone + Send + 'static {
+
+    let (action, waiters) = {
+        let mut locked = inner.lock().unwrap();
+        let action = locked.next_action(id);
+        (action, locked.take_waiters())
+    };
+
+    for complete in waiters {
+        complete.complete(());
+    }
+
+    match action {
+        Some(Action::Send(sender, val)) => {
+            let inner = inner.clone();
+
+            sender.send(val).receive(move |res| {
+                match res {
+                    Ok(sender) => {
+                        {
+                            let mut locked = inner.lock().unwrap();
+
+                            if let Some(sub) = locked.subs.iter_mut().find(|sub| sub.id == id) {
+                                sub.sender = Some(sender);
+                            }
+                        }
+
+                        poll_sub(&inner, id);
+                    }
+                    Err(_) => {
+                        // The subscriber stream has been dropped
+                        let waiters = {
+                            let mut locked = inner.lock().unwrap();
+                            locked.remove(id);
+                            locked.take_waiters()
+                        };
+
+                        for complete in waiters {
+                            complete.complete(());
+                        }
+                    }
+                }
+            });
+        }
+        Some(Action::Close(sender)) => drop(sender),
+        Some(Action::Fail(sender, e)) => sender.fail(e),
+        None => {}
+    }
+}
+
+/*
+ *
+ * ===== Lagged =====
+ *
+ */
+
+/// The error a subscriber stream fails with when it falls too far behind a
+/// broadcast created with `broadcast_lagged`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Lagged;
+
+impl Error for Lagged {
+    fn description(&self) -> &str {
+        "subscriber lagged"
+    }
+}
+
+impl fmt::Display for Lagged {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "subscriber lagged")
+    }
+}
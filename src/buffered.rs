@@ -0,0 +1,266 @@
+use {Async, AsyncResult, AsyncError, Cancel, Stream, Sender};
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+pub fn buffered<A, E>(source: Stream<A, E>, n: usize) -> Stream<A::Value, E>
+        where A: Async<Error=E>,
+              E: Send + 'static {
+
+    let (sender, stream) = Stream::pair();
+
+    let inner = Arc::new(Mutex::new(Inner {
+        source: Some(source),
+        n: n,
+        slots: VecDeque::new(),
+        head: 0,
+        end: None,
+        sender: None,
+        done: false,
+    }));
+
+    wait(&inner, sender);
+
+    stream
+}
+
+struct Inner<A: Async<Error=E>, E: Send + 'static> {
+    // The source is taken while waiting on its next value
+    source: Option<Stream<A, E>>,
+    n: usize,
+    // The async values in flight, in the order of the source
+    slots: VecDeque<Slot<A, E>>,
+    // The sequence number of the front slot
+    head: u64,
+    // Set once the source has ended, `None` is a successful end
+    end: Option<Option<AsyncError<E>>>,
+    // Present when the consumer is waiting on a value that is not available
+    // yet
+    sender: Option<Sender<A::Value, E>>,
+    // Set once the returned stream has ended, failed, or been dropped
+    done: bool,
+}
+
+enum Slot<A: Async<Error=E>, E: Send + 'static> {
+    // Waiting on the async value, holding its cancel token once the callback
+    // has been registered
+    Pending(Option<A::Cancel>),
+    Ready(AsyncResult<A::Value, E>),
+}
+
+enum Action<A: Async<Error=E>, E: Send + 'static> {
+    Send(Sender<A::Value, E>, A::Value),
+    End(Sender<A::Value, E>, Option<AsyncError<E>>),
+    Pull(Stream<A, E>),
+    Cancel(A::Cancel),
+}
+
+impl<A: Async<Error=E>, E: Send + 'static> Inner<A, E> {
+    // Returns the work to do now that the state has changed. Must be invoked
+    // with the lock held and the actions performed once it is released.
+    fn actions(&mut self) -> Vec<Action<A, E>> {
+        let mut actions = vec![];
+
+        if self.done {
+            return actions;
+        }
+
+        let ready = match self.slots.front() {
+            Some(&Slot::Ready(_)) => true,
+            _ => false,
+        };
+
+        if self.sender.is_some() {
+            if ready {
+                let sender = self.sender.take().unwrap();
+
+                self.head += 1;
+
+                let res = match self.slots.pop_front().unwrap() {
+                    Slot::Ready(res) => res,
+                    Slot::Pending(_) => unreachable!(),
+                };
+
+                match res {
+                    Ok(val) => actions.push(Action::Send(sender, val)),
+                    Err(e) => {
+                        actions.extend(self.finish());
+                        actions.push(Action::End(sender, Some(e)));
+                        return actions;
+                    }
+                }
+            } else if self.slots.is_empty() && self.end.is_some() {
+                let sender = self.sender.take().unwrap();
+                let end = self.end.take().unwrap();
+
+                actions.extend(self.finish());
+                actions.push(Action::End(sender, end));
+                return actions;
+            }
+        }
+
+        if self.slots.len() < self.n {
+            if let Some(source) = self.source.take() {
+                actions.push(Action::Pull(source));
+            }
+        }
+
+        actions
+    }
+
+    // Returns the slot of the async value with the given sequence number, if
+    // it is still in flight or waiting to be sent
+    fn slot_mut(&mut self, seq: u64) -> Option<&mut Slot<A, E>> {
+        if seq < self.head {
+            return None;
+        }
+
+        let idx = (seq - self.head) as usize;
+        self.slots.get_mut(idx)
+    }
+
+    // Drops the source and returns the actions canceling the async values
+    // still in flight
+    fn finish(&mut self) -> Vec<Action<A, E>> {
+        self.done = true;
+        self.source = None;
+
+        self.slots.drain(..)
+            .filter_map(|slot| {
+                match slot {
+                    Slot::Pending(Some(cancel)) => Some(Action::Cancel(cancel)),
+                    _ => None,
+                }
+            })
+            .collect()
+    }
+}
+
+fn run<A, E>(inner: &Arc<Mutex<Inner<A, E>>>, actions: Vec<Action<A, E>>)
+        where A: Async<Error=E>,
+              E: Send + 'static {
+
+    for action in actions {
+        match action {
+            Action::Send(sender, val) => wait(inner, sender.send(val)),
+            Action::End(sender, None) => drop(sender),
+            Action::End(sender, Some(AsyncError::Failed(e))) => sender.fail(e),
+            Action::End(sender, Some(AsyncError::Aborted)) => sender.abort(),
+            Action::Pull(source) => pull(inner, source),
+            // Dropping the async value cancels its computation
+            Action::Cancel(cancel) => drop(cancel.cancel()),
+        }
+    }
+}
+
+// Waits for the consumer to be ready for the next value
+fn wait<A, E, S>(inner: &Arc<Mutex<Inner<A, E>>>, sender: S)
+        where A: Async<Error=E>,
+              E: Send + 'static,
+              S: Async<Value=Sender<A::Value, E>> {
+
+    let inner = inner.clone();
+
+    sender.receive(move |res| {
+        let actions = {
+            let mut locked = inner.lock().unwrap();
+
+            match res {
+                Ok(sender) => {
+                    locked.sender = Some(sender);
+                    locked.actions()
+                }
+                // The returned stream has been dropped
+                Err(_) => locked.finish(),
+            }
+        };
+
+        run(&inner, actions);
+    });
+}
+
+fn pull<A, E>(inner: &Arc<Mutex<Inner<A, E>>>, source: Stream<A, E>)
+        where A: Async<Error=E>,
+              E: Send + 'static {
+
+    let inner = inner.clone();
+
+    source.receive(move |res| {
+        let (started, actions) = {
+            let mut locked = inner.lock().unwrap();
+            let mut started = None;
+
+            // The returned stream is done, drop the value and the rest of the
+            // source outside of the lock
+            if locked.done {
+                drop(locked);
+                drop(res);
+                return;
+            }
+
+            match res {
+                Ok(Some((async, rest))) => {
+                    let seq = locked.head + locked.slots.len() as u64;
+
+                    locked.slots.push_back(Slot::Pending(None));
+                    locked.source = Some(rest);
+                    started = Some((seq, async));
+                }
+                Ok(None) => locked.end = Some(None),
+                Err(e) => locked.end = Some(Some(e)),
+            }
+
+            (started, locked.actions())
+        };
+
+        run(&inner, actions);
+
+        if let Some((seq, async)) = started {
+            start(&inner, seq, async);
+        }
+    });
+}
+
+// Runs the async value, storing its result in its slot once it completes
+fn start<A, E>(inner: &Arc<Mutex<Inner<A, E>>>, seq: u64, async: A)
+        where A: Async<Error=E>,
+              E: Send + 'static {
+
+    let cancel = {
+        let inner = inner.clone();
+
+        async.ready(move |async| {
+            let actions = {
+                let mut locked = inner.lock().unwrap();
+
+                if let Some(slot) = locked.slot_mut(seq) {
+                    *slot = Slot::Ready(async.expect());
+                }
+
+                locked.actions()
+            };
+
+            run(&inner, actions);
+        })
+    };
+
+    // Store the cancel token, unless the value has already completed or the
+    // returned stream is done, in which case the token is used right away.
+    let cancel = {
+        let mut locked = inner.lock().unwrap();
+
+        match locked.slot_mut(seq) {
+            Some(slot) => {
+                if let Slot::Pending(ref mut token) = *slot {
+                    *token = Some(cancel);
+                }
+
+                None
+            }
+            None => Some(cancel),
+        }
+    };
+
+    if let Some(cancel) = cancel {
+        drop(cancel.cancel());
+    }
+}
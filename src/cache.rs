@@ -0,0 +1,41 @@
+use {Future, SharedFuture};
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::{Arc, Mutex};
+
+/// Returns the future cached in `store` for `key`, invoking `compute` to
+/// create and cache it if there is none yet.
+///
+/// Futures are cached as `SharedFuture`s, so every caller for the same key
+/// awaits the same computation, whether it has completed or not. The store is
+/// locked while `compute` is invoked, so concurrent calls for a missing key
+/// only compute it once. `compute` must therefore not itself use the store.
+///
+/// Failed futures stay cached as well; remove the entry from the store to
+/// compute it again.
+///
+/// ```
+/// use eventual::*;
+/// use std::collections::HashMap;
+/// use std::sync::{Arc, Mutex};
+///
+/// let store = Arc::new(Mutex::new(HashMap::new()));
+///
+/// let a = cache("answer", store.clone(), || Future::<i32, ()>::of(42));
+/// let b = cache("answer", store.clone(), || panic!("already cached"));
+///
+/// assert_eq!(Ok(42), a.await());
+/// assert_eq!(Ok(42), b.await());
+/// ```
+pub fn cache<K, V, E, F>(key: K, store: Arc<Mutex<HashMap<K, SharedFuture<V, E>>>>, compute: F) -> SharedFuture<V, E>
+        where K: Eq + Hash,
+              V: Clone + Send + 'static,
+              E: Clone + Send + 'static,
+              F: FnOnce() -> Future<V, E> {
+
+    let mut store = store.lock().unwrap();
+
+    store.entry(key)
+        .or_insert_with(|| compute().shared())
+        .clone()
+}
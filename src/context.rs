@@ -0,0 +1,107 @@
+use {Async, AsyncResult, Cancel, Future};
+use std::fmt;
+
+/// An async value carrying a context value, such as a trace context, through
+/// the computation chain. See `Async::with_context`.
+///
+/// `ContextualAsync` is itself async, and callbacks registered through
+/// `and_then_ctx` and `map_ctx` are handed a reference to the context. The
+/// returned async values carry the same context along.
+pub struct ContextualAsync<A: Async, C: Clone + Send + 'static> {
+    async: A,
+    ctx: C,
+}
+
+pub fn new<A: Async, C: Clone + Send + 'static>(async: A, ctx: C) -> ContextualAsync<A, C> {
+    ContextualAsync { async: async, ctx: ctx }
+}
+
+impl<A: Async, C: Clone + Send + 'static> ContextualAsync<A, C> {
+    /// Returns a reference to the context value.
+    pub fn context(&self) -> &C {
+        &self.ctx
+    }
+
+    /// Returns the wrapped async value, discarding the context.
+    pub fn into_inner(self) -> A {
+        self.async
+    }
+
+    /// Same as `Async::and_then`, except that the callback is also handed the
+    /// context, which is carried along by the returned async value.
+    ///
+    /// ```
+    /// use eventual::*;
+    ///
+    /// let f = Future::<i32, ()>::of(1).with_context("trace-1")
+    ///     .and_then_ctx(|ctx, v| Ok(format!("{}: {}", ctx, v)));
+    ///
+    /// assert_eq!("trace-1", *f.context());
+    /// assert_eq!(Ok("trace-1: 1".to_string()), f.await());
+    /// ```
+    pub fn and_then_ctx<F, U>(self, f: F) -> ContextualAsync<Future<U::Value, A::Error>, C>
+            where F: FnOnce(&C, A::Value) -> U + Send + 'static,
+                  U: Async<Error=A::Error> {
+
+        let ctx = self.ctx.clone();
+        new(self.async.and_then(move |val| f(&ctx, val)), self.ctx)
+    }
+
+    /// Same as `Async::map`, except that the callback is also handed the
+    /// context, which is carried along by the returned async value.
+    pub fn map_ctx<F, U>(self, f: F) -> ContextualAsync<Future<U, A::Error>, C>
+            where F: FnOnce(&C, A::Value) -> U + Send + 'static,
+                  U: Send + 'static {
+
+        let ctx = self.ctx.clone();
+        new(self.async.map(move |val| f(&ctx, val)), self.ctx)
+    }
+}
+
+impl<A: Async, C: Clone + Send + 'static> Async for ContextualAsync<A, C> {
+    type Value = A::Value;
+    type Error = A::Error;
+    type Cancel = ContextualCancel<A, C>;
+
+    fn is_ready(&self) -> bool {
+        self.async.is_ready()
+    }
+
+    fn is_err(&self) -> bool {
+        self.async.is_err()
+    }
+
+    fn poll(self) -> Result<AsyncResult<A::Value, A::Error>, ContextualAsync<A, C>> {
+        let ContextualAsync { async, ctx } = self;
+        async.poll().map_err(move |async| new(async, ctx))
+    }
+
+    fn ready<F>(self, f: F) -> ContextualCancel<A, C>
+            where F: FnOnce(ContextualAsync<A, C>) + Send + 'static {
+
+        let ContextualAsync { async, ctx } = self;
+        let c = ctx.clone();
+        let cancel = async.ready(move |async| f(new(async, c)));
+
+        ContextualCancel { cancel: cancel, ctx: ctx }
+    }
+}
+
+impl<A: Async + fmt::Debug, C: Clone + Send + fmt::Debug + 'static> fmt::Debug for ContextualAsync<A, C> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "ContextualAsync {{ async: {:?}, ctx: {:?} }}", self.async, self.ctx)
+    }
+}
+
+/// The cancel token of a `ContextualAsync`.
+pub struct ContextualCancel<A: Async, C: Clone + Send + 'static> {
+    cancel: A::Cancel,
+    ctx: C,
+}
+
+impl<A: Async, C: Clone + Send + 'static> Cancel<ContextualAsync<A, C>> for ContextualCancel<A, C> {
+    fn cancel(self) -> Option<ContextualAsync<A, C>> {
+        let ContextualCancel { cancel, ctx } = self;
+        cancel.cancel().map(move |async| new(async, ctx))
+    }
+}
@@ -37,6 +37,17 @@ impl<T: Send + 'static, E: Send + 'static> Core<T, E> {
         self.inner().consumer_is_err()
     }
 
+    /// Returns a reference to the underlying value if it has been realized,
+    /// without consuming it.
+    pub fn consumer_peek(&self) -> Option<&AsyncResult<T, E>> {
+        self.inner().consumer_peek()
+    }
+
+    /// Returns true if both handles point to the same core.
+    pub fn is_same(&self, other: &Core<T, E>) -> bool {
+        self.ptr == other.ptr
+    }
+
     /// Returns the underlying value if it has been realized, None otherwise.
     pub fn consumer_poll(&mut self) -> Option<AsyncResult<T, E>> {
         self.inner_mut().consumer_poll()
@@ -222,6 +233,14 @@ impl<T: Send + 'static, E: Send + 'static> CoreInner<T, E> {
             .is_err()
     }
 
+    pub fn consumer_peek(&self) -> Option<&AsyncResult<T, E>> {
+        if !self.state.load(Acquire).is_ready() {
+            return None;
+        }
+
+        self.val.as_ref()
+    }
+
     pub fn consumer_poll(&mut self) -> Option<AsyncResult<T, E>> {
         let curr = self.state.load(Relaxed);
 
@@ -817,8 +836,8 @@ impl AtomicState {
                 ConsumerWait | ProducerNotify => {
                     curr.with_lifecycle(ConsumerWait).with_producing()
                 }
-                Canceled => {
-                    curr.with_producing()
+                Canceled | ProducerNotifyCanceled => {
+                    curr.with_lifecycle(Canceled).with_producing()
                 }
                 _ => panic!("unexpected state {:?}", curr),
             };
@@ -914,7 +933,7 @@ impl State {
 
     fn is_producer_notify(&self) -> bool {
         match self.lifecycle() {
-            ProducerNotify => true,
+            ProducerNotify | ProducerNotifyCanceled => true,
             _ => false,
         }
     }
@@ -0,0 +1,111 @@
+use {Async, AsyncResult, BoxedReceive, Cancel};
+use std::marker::PhantomData;
+use std::fmt;
+
+/// An async value of any type, with its concrete `Async` implementation and
+/// `Cancel` token type erased.
+///
+/// This allows async values of different types that share a value and error
+/// type, such as futures and combinators of them, to be stored in the same
+/// collection.
+pub struct ErasedAsync<V: Send + 'static, E: Send + 'static> {
+    inner: Box<DynAsync<V, E>>,
+}
+
+/// An alias of `ErasedAsync`, see `Async::boxed`.
+pub type BoxedAsync<V, E> = ErasedAsync<V, E>;
+
+pub fn new<A: Async>(async: A) -> ErasedAsync<A::Value, A::Error> {
+    ErasedAsync { inner: Box::new(async) }
+}
+
+impl<V: Send + 'static, E: Send + 'static> Async for ErasedAsync<V, E> {
+    type Value = V;
+    type Error = E;
+    type Cancel = ErasedCancel<V, E>;
+
+    fn is_ready(&self) -> bool {
+        self.inner.is_ready_boxed()
+    }
+
+    fn is_err(&self) -> bool {
+        self.inner.is_err_boxed()
+    }
+
+    fn poll(self) -> Result<AsyncResult<V, E>, ErasedAsync<V, E>> {
+        self.inner.poll_boxed()
+    }
+
+    fn ready<F: FnOnce(ErasedAsync<V, E>) + Send + 'static>(self, f: F) -> ErasedCancel<V, E> {
+        ErasedCancel { inner: self.inner.ready_boxed(Box::new(f)) }
+    }
+}
+
+impl<V: Send + 'static, E: Send + 'static> fmt::Debug for ErasedAsync<V, E> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "ErasedAsync {{ ... }}")
+    }
+}
+
+/// The cancel token of an `ErasedAsync`.
+pub struct ErasedCancel<V: Send + 'static, E: Send + 'static> {
+    inner: Box<DynCancel<V, E>>,
+}
+
+impl<V: Send + 'static, E: Send + 'static> Cancel<ErasedAsync<V, E>> for ErasedCancel<V, E> {
+    fn cancel(self) -> Option<ErasedAsync<V, E>> {
+        self.inner.cancel_boxed()
+    }
+}
+
+/*
+ *
+ * ===== Virtual dispatch =====
+ *
+ */
+
+// Object safe version of `Async`
+trait DynAsync<V: Send + 'static, E: Send + 'static> : Send + 'static {
+    fn is_ready_boxed(&self) -> bool;
+
+    fn is_err_boxed(&self) -> bool;
+
+    fn poll_boxed(self: Box<Self>) -> Result<AsyncResult<V, E>, ErasedAsync<V, E>>;
+
+    fn ready_boxed(self: Box<Self>, f: Box<BoxedReceive<ErasedAsync<V, E>>>) -> Box<DynCancel<V, E>>;
+}
+
+impl<A: Async> DynAsync<A::Value, A::Error> for A {
+    fn is_ready_boxed(&self) -> bool {
+        self.is_ready()
+    }
+
+    fn is_err_boxed(&self) -> bool {
+        self.is_err()
+    }
+
+    fn poll_boxed(self: Box<A>) -> Result<AsyncResult<A::Value, A::Error>, ErasedAsync<A::Value, A::Error>> {
+        (*self).poll().map_err(new)
+    }
+
+    fn ready_boxed(self: Box<A>, f: Box<BoxedReceive<ErasedAsync<A::Value, A::Error>>>) -> Box<DynCancel<A::Value, A::Error>> {
+        let cancel = (*self).ready(move |async| f.receive_boxed(new(async)));
+        Box::new(Erased::<A> { cancel: cancel, marker: PhantomData })
+    }
+}
+
+// Object safe version of `Cancel`
+trait DynCancel<V: Send + 'static, E: Send + 'static> : Send + 'static {
+    fn cancel_boxed(self: Box<Self>) -> Option<ErasedAsync<V, E>>;
+}
+
+struct Erased<A: Async> {
+    cancel: A::Cancel,
+    marker: PhantomData<A>,
+}
+
+impl<A: Async> DynCancel<A::Value, A::Error> for Erased<A> {
+    fn cancel_boxed(self: Box<Erased<A>>) -> Option<ErasedAsync<A::Value, A::Error>> {
+        self.cancel.cancel().map(new)
+    }
+}
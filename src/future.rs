@@ -1,21 +1,36 @@
 use super::{
     receipt,
+    select,
+    shared,
     stream,
+    timer,
     Async,
     Pair,
     Stream,
     Cancel,
     Receipt,
+    SharedFuture,
+    BoxedError,
     AsyncResult,
     AsyncError
 };
 use super::core::{self, Core};
+use syncbox::{Run, Task, TaskBox};
+use time::Duration;
+use std::any::Any;
 use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
 
 /* TODO:
  * - Add AsyncVal trait that impls all the various monadic fns
  */
 
+/// A value that will be available at some point in the future.
+///
+/// A `Future` has a single consumer and is intentionally not `Clone`: its
+/// value is moved out when it completes. To hand the same result to several
+/// consumers without computing it twice, convert it with `Future::shared`,
+/// which returns a cloneable `SharedFuture`.
 #[must_use = "futures are lazy and do nothing unless consumed"]
 pub struct Future<T: Send + 'static, E: Send + 'static> {
     core: Option<Core<T, E>>,
@@ -29,6 +44,65 @@ impl<T: Send +'static, E: Send +'static> Future<T, E> {
         (Complete { core: Some(core) }, future)
     }
 
+    /// Returns a future with its associated `Complete`, where the future is
+    /// aborted if the `Complete` has not been used within `dur`.
+    ///
+    /// Once the deadline elapses, the `Complete` observes the future as
+    /// canceled, which it can check with `Complete::is_cancelled`.
+    ///
+    /// ```
+    /// # extern crate eventual;
+    /// # extern crate time;
+    /// use eventual::*;
+    /// use time::Duration;
+    /// # fn main() {
+    ///
+    /// let (c, f) = Future::<i32, ()>::pair_with_timeout(Duration::milliseconds(10));
+    ///
+    /// assert_eq!(Err(AsyncError::Aborted), f.await());
+    /// assert!(c.is_cancelled());
+    /// # }
+    /// ```
+    pub fn pair_with_timeout(dur: Duration) -> (Complete<T, E>, Future<T, E>) {
+        let (complete, future) = Future::pair();
+        let (tx, ret) = Future::pair();
+
+        // Started now so that the deadline is relative to the creation of
+        // the pair rather than to the consumer registering interest.
+        let deadline = timer::shared()
+            .timeout_ms(timer::to_ms(dur))
+            .map_err(|_| unreachable!());
+
+        tx.receive(move |res| {
+            if let Ok(tx) = res {
+                select((future, deadline)).receive(move |res| {
+                    match res {
+                        Ok((0, (future, _))) => {
+                            match future.expect() {
+                                Ok(v) => tx.complete(v),
+                                Err(AsyncError::Failed(e)) => tx.fail(e),
+                                Err(AsyncError::Aborted) => drop(tx),
+                            }
+                        }
+                        // The deadline elapsed first, dropping the future
+                        // cancels it. This happens before the consumer is
+                        // notified so that it observes the cancellation.
+                        Ok((_, (future, _))) => {
+                            drop(future);
+                            drop(tx);
+                        }
+                        // `select` drops the remaining async value before
+                        // failing.
+                        Err(AsyncError::Failed(e)) => tx.fail(e),
+                        Err(AsyncError::Aborted) => drop(tx),
+                    }
+                });
+            }
+        });
+
+        (complete, ret)
+    }
+
     /// Returns a future that will immediately succeed with the supplied value.
     ///
     /// ```
@@ -58,6 +132,32 @@ impl<T: Send +'static, E: Send +'static> Future<T, E> {
         Future { core: Some(core) }
     }
 
+    /// Returns a future that never completes, useful as a placeholder or as
+    /// a branch of `select` that should never win.
+    ///
+    /// Note that calling `await` on the returned future blocks the thread
+    /// forever.
+    pub fn never() -> Future<T, E> {
+        // There is no producer, so nothing will ever complete the core
+        Future { core: Some(Core::new()) }
+    }
+
+    /// Returns a future that will immediately succeed or fail with the
+    /// supplied result.
+    ///
+    /// ```
+    /// use eventual::*;
+    ///
+    /// let res: Result<i32, &'static str> = "123".parse().map_err(|_| "invalid");
+    /// assert_eq!(Ok(123), Future::from_result(res).await());
+    /// ```
+    pub fn from_result(res: Result<T, E>) -> Future<T, E> {
+        match res {
+            Ok(val) => Future::of(val),
+            Err(err) => Future::error(err),
+        }
+    }
+
     /// Returns a future that won't kick off its async action until
     /// a consumer registers interest.
     ///
@@ -110,7 +210,7 @@ impl<T: Send +'static, E: Send +'static> Future<T, E> {
     pub fn map<F, U>(self, f: F) -> Future<U, E>
         where F: FnOnce(T) -> U + Send + 'static,
               U: Send + 'static {
-        self.and_then(move |val| Ok(f(val)))
+        Async::map(self, f)
     }
 
     /// Returns a new future with an identical value as the original. If the
@@ -119,21 +219,82 @@ impl<T: Send +'static, E: Send +'static> Future<T, E> {
     pub fn map_err<F, U>(self, f: F) -> Future<T, U>
             where F: FnOnce(E) -> U + Send + 'static,
                   U: Send + 'static {
-        let (complete, future) = Future::pair();
+        Async::map_err(self, f)
+    }
+
+    /// Returns a new future with an identical value or error as the original,
+    /// after invoking the callback with a reference to the result.
+    ///
+    /// The callback is invoked however the original future settles, which
+    /// makes it suitable for releasing resources. If the returned future is
+    /// dropped before its value is requested, the original future is canceled
+    /// and the callback is invoked with `AsyncError::Aborted`. Panics in the
+    /// callback are not caught; the returned future is then aborted.
+    ///
+    /// ```
+    /// use eventual::*;
+    /// use std::sync::mpsc::channel;
+    ///
+    /// let (tx, rx) = channel();
+    /// let f = Future::<i32, &'static str>::error("nope")
+    ///     .on_complete(move |res| tx.send(res.is_err()).unwrap());
+    ///
+    /// assert_eq!(Err(AsyncError::Failed("nope")), f.await());
+    /// assert_eq!(Ok(true), rx.recv());
+    /// ```
+    pub fn on_complete<F>(self, f: F) -> Future<T, E>
+            where F: FnOnce(&AsyncResult<T, E>) + Send + 'static {
+        let (complete, ret) = Future::pair();
 
         complete.receive(move |res| {
-            if let Ok(complete) = res {
-                self.receive(move |res| {
-                    match res {
-                        Ok(v) => complete.complete(v),
-                        Err(AsyncError::Failed(e)) => complete.fail(f(e)),
-                        Err(AsyncError::Aborted) => drop(complete),
-                    }
-                });
+            match res {
+                Ok(complete) => {
+                    self.receive(move |res| {
+                        f(&res);
+
+                        match res {
+                            Ok(v) => complete.complete(v),
+                            Err(AsyncError::Failed(e)) => complete.fail(e),
+                            Err(AsyncError::Aborted) => drop(complete),
+                        }
+                    });
+                }
+                Err(_) => {
+                    drop(self);
+                    f(&Err(AsyncError::Aborted));
+                }
             }
         });
 
-        future
+        ret
+    }
+
+    /// Returns a new future with an identical value or error as the original,
+    /// invoking the callback if and only if the original future is aborted.
+    ///
+    /// This includes the returned future being dropped before its value is
+    /// requested, which cancels the original future. See `on_complete`.
+    ///
+    /// ```
+    /// use eventual::*;
+    /// use std::sync::mpsc::channel;
+    ///
+    /// let (tx, rx) = channel();
+    /// let (c, f) = Future::<i32, ()>::pair();
+    /// let f = f.on_cancel(move || tx.send("canceled").unwrap());
+    ///
+    /// drop(c);
+    ///
+    /// assert!(f.await().unwrap_err().is_aborted());
+    /// assert_eq!(Ok("canceled"), rx.recv());
+    /// ```
+    pub fn on_cancel<F>(self, f: F) -> Future<T, E>
+            where F: FnOnce() + Send + 'static {
+        self.on_complete(move |res| {
+            if let Err(AsyncError::Aborted) = *res {
+                f();
+            }
+        })
     }
 
     /*
@@ -147,6 +308,25 @@ impl<T: Send +'static, E: Send +'static> Future<T, E> {
     }
 }
 
+impl<T: Clone + Send + 'static, E: Clone + Send + 'static> Future<T, E> {
+    /// Returns a `SharedFuture` that can be cloned, allowing multiple
+    /// consumers to use the result of this future, which is only computed
+    /// once.
+    ///
+    /// ```
+    /// use eventual::*;
+    ///
+    /// let shared = Future::<i32, ()>::spawn(|| 21).shared();
+    /// let doubled = shared.clone().map(|v| v * 2);
+    ///
+    /// assert_eq!(Ok(21), shared.await());
+    /// assert_eq!(Ok(42), doubled.await());
+    /// ```
+    pub fn shared(self) -> SharedFuture<T, E> {
+        shared::new(self)
+    }
+}
+
 impl<T: Send + 'static> Future<T, ()> {
     /// Returns a `Future` representing the completion of the given closure.
     /// The closure will be executed on a newly spawned thread.
@@ -163,16 +343,101 @@ impl<T: Send + 'static> Future<T, ()> {
     pub fn spawn<F>(f: F) -> Future<T, ()>
         where F: FnOnce() -> T + Send + 'static {
 
-        use std::thread;
+        Future::spawn_with_executor(f, &ThreadSpawner)
+    }
+
+    /// Returns a `Future` representing the completion of the given closure.
+    /// The closure will be executed by the given task runner, such as a
+    /// `ThreadPool`.
+    ///
+    /// ```
+    /// use eventual::*;
+    ///
+    /// let pool = ThreadPool::new(1);
+    /// let future = Future::spawn_with_executor(|| 42, &pool);
+    ///
+    /// assert_eq!(42, future.await().unwrap());
+    /// ```
+    pub fn spawn_with_executor<F, R>(f: F, executor: &R) -> Future<T, ()>
+        where F: FnOnce() -> T + Send + 'static,
+              R: Run<Box<TaskBox>> {
+
         let (complete, future) = Future::pair();
 
-        // Spawn the thread
-        thread::spawn(move || complete.complete(f()));
+        executor.run(Box::new(move || complete.complete(f())));
 
         future
     }
 }
 
+// Runs each task on a newly spawned thread
+pub struct ThreadSpawner;
+
+impl Run<Box<TaskBox>> for ThreadSpawner {
+    fn run(&self, task: Box<TaskBox>) {
+        use std::thread;
+
+        // Spawn the thread
+        thread::spawn(move || task.run());
+    }
+}
+
+// Invokes `f` on the task runner and forwards the completion of the async
+// value it returns to `complete`.
+pub fn run_forward<F, U, R>(task_runner: &R, complete: Complete<U::Value, U::Error>, f: F)
+        where F: FnOnce() -> U + Send + 'static,
+              U: Async,
+              R: Run<Box<TaskBox>> {
+
+    task_runner.run(Box::new(move || {
+        f().receive(move |res| {
+            match res {
+                Ok(u) => complete.complete(u),
+                Err(AsyncError::Failed(e)) => complete.fail(e),
+                Err(AsyncError::Aborted) => drop(complete),
+            }
+        });
+    }));
+}
+
+impl<T: Send + 'static> Future<T, Box<Any + Send>> {
+    /// Creates a new `Future`, returning it with the associated `Complete`,
+    /// for a computation whose panics are forwarded to the consumer. See
+    /// `Complete::complete_catching`.
+    ///
+    /// ```
+    /// use eventual::*;
+    ///
+    /// let (c, f) = Future::<i32, _>::panic_aware();
+    ///
+    /// f.receive(|res| {
+    ///     // The panic is caught and can be re-raised with
+    ///     // `std::panic::resume_unwind`
+    ///     assert!(res.is_err());
+    /// });
+    ///
+    /// c.complete_catching(|| panic!("boom"));
+    /// ```
+    pub fn panic_aware() -> (Complete<T, Box<Any + Send>>, Future<T, Box<Any + Send>>) {
+        Future::pair()
+    }
+}
+
+impl<T: Send + 'static, E: Send + 'static> Future<Future<T, E>, E> {
+    /// Returns a future that completes with the value of the inner future
+    /// once both the original future and the future it yields complete.
+    ///
+    /// ```
+    /// use eventual::*;
+    ///
+    /// let nested = Future::<_, ()>::of(Future::of(123));
+    /// assert_eq!(Ok(123), nested.flatten().await());
+    /// ```
+    pub fn flatten(self) -> Future<T, E> {
+        self.and_then(|inner| inner)
+    }
+}
+
 impl<T: Send + 'static, E: Send + 'static> Future<Option<(T, Stream<T, E>)>, E> {
     /// An adapter that converts any future into a one-value stream
     pub fn to_stream(mut self) -> Stream<T, E> {
@@ -227,7 +492,16 @@ impl<T: Send + 'static, E: Send + 'static> Pair for Future<T, E> {
 
 impl<T: Send + 'static, E: Send + 'static> fmt::Debug for Future<T, E> {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
-        write!(fmt, "Future {{ ... }}")
+        // The value itself is not shown, so that `T: Debug` is not required
+        let state = if !self.is_ready() {
+            "Pending"
+        } else if self.is_err() {
+            "Error"
+        } else {
+            "Complete"
+        };
+
+        write!(fmt, "Future({})", state)
     }
 }
 
@@ -288,6 +562,25 @@ impl<T: Send + 'static, E: Send + 'static> Complete<T, E> {
         core::take(&mut self.core).complete(Ok(val), true);
     }
 
+    /// Fulfill the associated promise with a value, for callers that expect
+    /// the consumer to still be interested in it.
+    ///
+    /// Completing consumes the `Complete`, so a promise can never be
+    /// fulfilled twice. `complete` silently discards the value when the
+    /// consumer has dropped the future, this method treats it as a bug
+    /// instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the future has been canceled.
+    pub fn send(self, val: T) {
+        if self.is_cancelled() {
+            panic!("sent a value to a canceled future");
+        }
+
+        self.complete(val);
+    }
+
     /// Reject the associated promise with an error. The error
     /// will be wrapped in `Async::Error::Failed`.
     pub fn fail(mut self, err: E) {
@@ -306,6 +599,68 @@ impl<T: Send + 'static, E: Send + 'static> Complete<T, E> {
         core::get(&self.core).producer_is_err()
     }
 
+    /// Returns true if the consumer has lost interest in the value, in which
+    /// case there is no point in computing it.
+    pub fn is_cancelled(&self) -> bool {
+        self.is_err()
+    }
+
+    /// Returns a `Complete` that forwards its result to the original one and
+    /// registers a callback that is invoked if the consumer loses interest
+    /// in the value.
+    ///
+    /// The returned `Complete` only becomes ready once the consumer of the
+    /// original one registers interest. If the consumer loses interest before
+    /// that, the returned `Complete` is cancelled before the callback is
+    /// invoked. If it loses interest afterwards, for example by canceling its
+    /// `ready` callback, the callback is invoked once the returned `Complete`
+    /// is completed or dropped.
+    ///
+    /// ```
+    /// use eventual::*;
+    /// use std::sync::mpsc::channel;
+    ///
+    /// let (tx, rx) = channel();
+    /// let (c, f) = Future::<i32, ()>::pair();
+    ///
+    /// let c = c.on_cancel(move || tx.send("canceled").unwrap());
+    /// drop(f);
+    ///
+    /// assert_eq!(Ok("canceled"), rx.recv());
+    /// assert!(c.is_cancelled());
+    /// ```
+    pub fn on_cancel<F: FnOnce() + Send + 'static>(self, f: F) -> Complete<T, E> {
+        let (complete, future) = Future::pair();
+
+        self.receive(move |res| {
+            match res {
+                Ok(outer) => {
+                    future.receive(move |res| {
+                        // The consumer may have lost interest after
+                        // registering it, in which case the value is
+                        // discarded.
+                        if outer.is_cancelled() {
+                            drop(outer);
+                            return f();
+                        }
+
+                        match res {
+                            Ok(v) => outer.complete(v),
+                            Err(AsyncError::Failed(e)) => outer.fail(e),
+                            Err(AsyncError::Aborted) => drop(outer),
+                        }
+                    });
+                }
+                Err(_) => {
+                    drop(future);
+                    f();
+                }
+            }
+        });
+
+        complete
+    }
+
     fn poll(mut self) -> Result<AsyncResult<Complete<T, E>, ()>, Complete<T, E>> {
         debug!("Complete::poll; is_ready={}", self.is_ready());
 
@@ -338,6 +693,46 @@ impl<T: Send + 'static, E: Send + 'static> Complete<T, E> {
     }
 }
 
+impl<T: Send + 'static> Complete<T, BoxedError> {
+    /// Reject the associated promise with a type-erased error, allowing
+    /// errors from heterogeneous sources to be piped into the same
+    /// `Complete`.
+    ///
+    /// Anything that converts into a `BoxedError` is accepted, including
+    /// already boxed errors, concrete `Error` types that are `Send + Sync`,
+    /// and strings.
+    ///
+    /// ```
+    /// use eventual::*;
+    /// use std::fmt;
+    ///
+    /// let (c, f) = Future::<(), BoxedError>::pair();
+    /// c.fail_with(fmt::Error);
+    ///
+    /// let err = f.await().unwrap_err().unwrap();
+    /// assert_eq!("an error occurred when formatting an argument", err.to_string());
+    /// ```
+    pub fn fail_with<U: Into<BoxedError>>(self, err: U) {
+        self.fail(err.into());
+    }
+}
+
+impl<T: Send + 'static> Complete<T, Box<Any + Send>> {
+    /// Fulfill the associated promise with the value returned by the
+    /// closure. If the closure panics, the promise is rejected with the
+    /// panic's payload instead of the panic unwinding the current thread.
+    ///
+    /// Only the closure itself is guarded. A panic in a callback registered
+    /// on the associated future, such as with `receive` or `ready`, still
+    /// unwinds the thread completing the promise.
+    pub fn complete_catching<F: FnOnce() -> T>(self, f: F) {
+        match panic::catch_unwind(AssertUnwindSafe(f)) {
+            Ok(val) => self.complete(val),
+            Err(payload) => self.fail(payload),
+        }
+    }
+}
+
 impl<T: Send + 'static, E: Send + 'static> Async for Complete<T, E> {
     type Value = Complete<T, E>;
     type Error = ();
@@ -1,9 +1,17 @@
-use super::{Async, Future, Complete, AsyncError};
+use super::{Async, Future, Complete, Cancel, AsyncError, sequence_n_ordered};
 use std::cell::UnsafeCell;
-use std::sync::Arc;
+use std::marker::PhantomData;
+use std::sync::{Arc, Mutex};
 use std::sync::atomic::{self, AtomicIsize};
 use std::sync::atomic::Ordering;
 
+/// Returns a future that completes with the values of all of the given async
+/// values, which may be a `Vec` of futures or a tuple of async values of
+/// different types.
+///
+/// The join fails fast: as soon as any of the async values fails, the
+/// returned future fails with the same error, without waiting on the others,
+/// and the ones that have not yet completed are canceled.
 pub fn join<J: Join<T, E>, T: Send + 'static, E: Send + 'static>(asyncs: J) -> Future<T, E> {
     let (complete, future) = Future::pair();
 
@@ -18,6 +26,34 @@ pub fn join<J: Join<T, E>, T: Send + 'static, E: Send + 'static>(asyncs: J) -> F
     future
 }
 
+/// Returns a future that completes with the values of all of the given
+/// futures, in the original order.
+///
+/// The futures run concurrently. If any of them fails, the returned future
+/// fails with the same error and the futures that have not yet completed are
+/// canceled.
+pub fn join_all<V, E>(futures: Vec<Future<V, E>>) -> Future<Vec<V>, E>
+        where V: Send + 'static, E: Send + 'static {
+    join(futures)
+}
+
+/// Returns a future that completes with the values of all of the given
+/// futures, in the original order, waiting on at most `limit` of them at
+/// once.
+///
+/// The next future is only waited on once one of the in-flight futures has
+/// completed. If any of them fails, the returned future fails right away with
+/// the same error, the futures that are still in flight are canceled and the
+/// ones that were not yet waited on are dropped.
+///
+/// # Panics
+///
+/// Panics if `limit` is 0.
+pub fn join_with_limit<V, E>(futures: Vec<Future<V, E>>, limit: usize) -> Future<Vec<V>, E>
+        where V: Send + 'static, E: Send + 'static {
+    sequence_n_ordered(futures, limit)
+}
+
 pub trait Join<T: Send + 'static, E: Send + 'static> : Sized + Send + 'static {
     fn join(self, complete: Complete<T, E>);
 }
@@ -42,22 +78,6 @@ impl<T> Partial<Vec<T>> for Vec<Option<T>> {
     }
 }
 
-/// In progress completed values for 2-tuples
-///
-impl<T1, T2> Partial<(T1, T2)> for (Option<T1>, Option<T2>) {
-    fn consume(&mut self) -> (T1, T2) {
-        (self.0.take().unwrap(), self.1.take().unwrap())
-    }
-}
-
-/// In progress completed values for 3-tuples
-///
-impl<T1, T2, T3> Partial<(T1, T2, T3)> for (Option<T1>, Option<T2>, Option<T3>) {
-    fn consume(&mut self) -> (T1, T2, T3) {
-        (self.0.take().unwrap(), self.1.take().unwrap(), self.2.take().unwrap())
-    }
-}
-
 /// Join in progress state
 ///
 struct Progress<P: Partial<R>, R: Send + 'static, E: Send + 'static> {
@@ -103,6 +123,10 @@ impl<P: Partial<R>, R: Send + 'static, E: Send + 'static> Progress<P, R, E> {
         }
     }
 
+    fn is_failed(&self) -> bool {
+        self.inner().remaining.load(Ordering::Relaxed) < 0
+    }
+
     fn vals_mut<'a>(&'a self) -> &'a mut P {
         &mut self.inner_mut().vals
     }
@@ -139,34 +163,45 @@ macro_rules! expr {
 }
 
 macro_rules! component {
-    ($async:ident, $progress:ident, $id:tt) => {{
-        let $progress = $progress.clone();
-
-        $async.receive(move |res| {
-            debug!(concat!("dependent future complete; id=", $id, "; success={}"), res.is_ok());
-
-            // Get a pointer to the value staging area (Option<T>). Values will
-            // be stored here until the join is complete
-            let slot = expr!(&mut $progress.vals_mut().$id);
-
-            match res {
-                Ok(v) => {
-                    // Set the value
-                    *slot = Some(v);
-
-                    // Track that the value has been received
-                    if $progress.dec() == 0 {
-                        debug!("last future completed -- completing join");
-                        // If all values have been received, successfully
-                        // complete the future
-                        $progress.succeed();
+    ($A:ident, $async:ident, $progress:ident, $tokens:ident, $id:tt) => {{
+        let cancel = {
+            let $progress = $progress.clone();
+            let $tokens = $tokens.clone();
+
+            $async.ready(move |async| {
+                debug!(concat!("dependent future complete; id=", $id, "; success={}"), !async.is_err());
+
+                // Get a pointer to the value staging area (Option<T>). Values will
+                // be stored here until the join is complete
+                let slot = expr!(&mut $progress.vals_mut().$id);
+
+                match async.expect() {
+                    Ok(v) => {
+                        // Set the value
+                        *slot = Some(v);
+
+                        // Track that the value has been received
+                        if $progress.dec() == 0 {
+                            debug!("last future completed -- completing join");
+                            // If all values have been received, successfully
+                            // complete the future
+                            $progress.succeed();
+                        }
+                    }
+                    Err(e) => {
+                        $progress.fail(e);
+                        cancel_all(&$tokens);
                     }
                 }
-                Err(e) => {
-                    $progress.fail(e);
-                }
-            }
-        });
+            })
+        };
+
+        $tokens.lock().unwrap()[$id] = Some(Token::<$A>::new(cancel));
+
+        // The join may have failed while the callback was being registered.
+        if $progress.is_failed() {
+            cancel_all(&$tokens);
+        }
     }};
 }
 
@@ -197,71 +232,140 @@ impl<A: Async> Join<Vec<A::Value>, A::Error> for Vec<A> {
             return;
         }
 
-        for (i, async) in self.into_iter().enumerate() {
-            let progress = progress.clone();
+        // Tokens for the registered callbacks. When the join fails, they are
+        // used to reclaim and drop the pending async values, which cancels
+        // their computations.
+        let tokens = new_tokens(self.len());
 
-            async.receive(move |res| {
-                debug!(concat!("dependent future complete; id={}; success={}"), i, res.is_ok());
-
-                // Get a pointer to the value staging area (Option<T>). Values will
-                // be stored here until the join is complete
-
-                let slot = &mut progress.vals_mut()[i];
-
-                match res {
-                    Ok(v) => {
-                        // Set the value
-                        *slot = Some(v);
-
-                        // Track that the value has been received
-                        if progress.dec() == 0 {
-                            debug!("last future completed -- completing join");
-                            // If all values have been received, successfully
-                            // complete the future
-                            progress.succeed();
+        for (i, async) in self.into_iter().enumerate() {
+            let cancel = {
+                let progress = progress.clone();
+                let tokens = tokens.clone();
+
+                async.ready(move |async| {
+                    debug!(concat!("dependent future complete; id={}; success={}"), i, !async.is_err());
+
+                    // Get a pointer to the value staging area (Option<T>). Values will
+                    // be stored here until the join is complete
+
+                    let slot = &mut progress.vals_mut()[i];
+
+                    match async.expect() {
+                        Ok(v) => {
+                            // Set the value
+                            *slot = Some(v);
+
+                            // Track that the value has been received
+                            if progress.dec() == 0 {
+                                debug!("last future completed -- completing join");
+                                // If all values have been received, successfully
+                                // complete the future
+                                progress.succeed();
+                            }
+                        }
+                        Err(e) => {
+                            progress.fail(e);
+                            cancel_all(&tokens);
                         }
                     }
-                    Err(e) => {
-                        progress.fail(e);
-                    }
-                }
-            });
+                })
+            };
+
+            tokens.lock().unwrap()[i] = Some(Token::<A>::new(cancel));
+
+            // The join may have failed while the callback was being
+            // registered.
+            if progress.is_failed() {
+                cancel_all(&tokens);
+            }
         }
     }
 }
 
 /*
  *
- * ===== Join for Tuples =====
+ * ===== Cancellation =====
  *
  */
 
-impl<A1: Async<Error=E>, A2: Async<Error=E>, E> Join<(A1::Value, A2::Value), E> for (A1, A2)
-        where E: Send + 'static,
-              A1::Value: Send + 'static,
-              A2::Value: Send + 'static {
+// The cancel tokens of the joined async values may be of different types (for
+// tuples), so they are boxed in order to be revoked uniformly when the join
+// fails.
+trait Revoke : Send + 'static {
+    fn revoke(self: Box<Self>);
+}
+
+struct Token<A: Async> {
+    cancel: A::Cancel,
+    marker: PhantomData<A>,
+}
 
-    fn join(self, complete: Complete<(<A1 as Async>::Value, <A2 as Async>::Value), E>) {
-        let (a1, a2) = self;
-        let p = Progress::new((None, None), complete, 2);
+impl<A: Async> Token<A> {
+    fn new(cancel: A::Cancel) -> Box<Revoke> {
+        Box::new(Token::<A> {
+            cancel: cancel,
+            marker: PhantomData,
+        })
+    }
+}
 
-        component!(a1, p, 0);
-        component!(a2, p, 1);
+impl<A: Async> Revoke for Token<A> {
+    fn revoke(self: Box<Token<A>>) {
+        drop(self.cancel.cancel());
     }
 }
 
-impl<A1: Async<Error=E>, A2: Async<Error=E>, A3: Async<Error=E>, E> Join<(A1::Value, A2::Value, A3::Value), E> for (A1, A2, A3)
-        where E: Send + 'static,
-              A1::Value: Send + 'static,
-              A2::Value: Send + 'static,
-              A3::Value: Send + 'static {
+type Tokens = Arc<Mutex<Vec<Option<Box<Revoke>>>>>;
+
+fn new_tokens(n: usize) -> Tokens {
+    Arc::new(Mutex::new((0..n).map(|_| None).collect()))
+}
 
-    fn join(self, complete: Complete<(A1::Value, A2::Value, A3::Value), E>) {
-        let (a1, a2, a3) = self;
-        let p = Progress::new((None, None, None), complete, 3);
+fn cancel_all(tokens: &Mutex<Vec<Option<Box<Revoke>>>>) {
+    let tokens: Vec<_> = tokens.lock().unwrap()
+        .iter_mut()
+        .filter_map(|t| t.take())
+        .collect();
 
-        component!(a1, p, 0);
-        component!(a2, p, 1);
-        component!(a3, p, 2);
+    // Canceling happens outside of the lock, dropping an async value may run
+    // arbitrary code.
+    for token in tokens {
+        token.revoke();
     }
 }
+
+/*
+ *
+ * ===== Join for Tuples =====
+ *
+ */
+
+macro_rules! join_tuple {
+    ($n:expr, $($A:ident => $a:ident, $id:tt);+) => {
+        /// In progress completed values for tuples
+        ///
+        impl<$($A),+> Partial<($($A,)+)> for ($(Option<$A>,)+) {
+            fn consume(&mut self) -> ($($A,)+) {
+                ($(expr!(self.$id).take().unwrap(),)+)
+            }
+        }
+
+        impl<$($A: Async<Error=E>),+, E: Send + 'static> Join<($($A::Value,)+), E> for ($($A,)+) {
+            fn join(self, complete: Complete<($($A::Value,)+), E>) {
+                let ($($a,)+) = self;
+                let p = Progress::new(($(None::<$A::Value>,)+), complete, $n);
+                let tokens = new_tokens($n);
+
+                $(component!($A, $a, p, tokens, $id);)+
+            }
+        }
+    };
+}
+
+join_tuple!(2, A1 => a1, 0; A2 => a2, 1);
+join_tuple!(3, A1 => a1, 0; A2 => a2, 1; A3 => a3, 2);
+join_tuple!(4, A1 => a1, 0; A2 => a2, 1; A3 => a3, 2; A4 => a4, 3);
+join_tuple!(5, A1 => a1, 0; A2 => a2, 1; A3 => a3, 2; A4 => a4, 3; A5 => a5, 4);
+join_tuple!(6, A1 => a1, 0; A2 => a2, 1; A3 => a3, 2; A4 => a4, 3; A5 => a5, 4; A6 => a6, 5);
+join_tuple!(7, A1 => a1, 0; A2 => a2, 1; A3 => a3, 2; A4 => a4, 3; A5 => a5, 4; A6 => a6, 5; A7 => a7, 6);
+join_tuple!(8, A1 => a1, 0; A2 => a2, 1; A3 => a3, 2; A4 => a4, 3; A5 => a5, 4; A6 => a6, 5; A7 => a7, 6; A8 => a8, 7);
@@ -0,0 +1,97 @@
+use {Future, Complete};
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::fmt;
+
+/// A cloneable handle that completes its associated `Future` the first time
+/// any clone of it is resolved, possibly from competing threads.
+///
+/// Once the latch has been resolved or failed, later calls are ignored. The
+/// future is aborted if every clone is dropped before that happens.
+///
+/// ```
+/// use eventual::*;
+/// use std::thread;
+///
+/// let (latch, future) = Latch::<usize, ()>::pair();
+///
+/// for i in 0..4 {
+///     let latch = latch.clone();
+///     thread::spawn(move || latch.resolve(i));
+/// }
+///
+/// assert!(future.await().unwrap() < 4);
+/// ```
+pub struct Latch<V: Send + 'static, E: Send + 'static> {
+    inner: Arc<Inner<V, E>>,
+}
+
+struct Inner<V: Send + 'static, E: Send + 'static> {
+    done: AtomicBool,
+    complete: Mutex<Option<Complete<V, E>>>,
+}
+
+impl<V: Send + 'static, E: Send + 'static> Latch<V, E> {
+    /// Returns a new latch along with the future it completes.
+    pub fn pair() -> (Latch<V, E>, Future<V, E>) {
+        let (complete, future) = Future::pair();
+
+        let latch = Latch {
+            inner: Arc::new(Inner {
+                done: AtomicBool::new(false),
+                complete: Mutex::new(Some(complete)),
+            }),
+        };
+
+        (latch, future)
+    }
+
+    /// Completes the associated future with `val` unless the latch has
+    /// already been resolved or failed. Returns true if this call won.
+    pub fn resolve(&self, val: V) -> bool {
+        match self.take() {
+            Some(complete) => {
+                complete.complete(val);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Fails the associated future with `err` unless the latch has already
+    /// been resolved or failed. Returns true if this call won.
+    pub fn fail(&self, err: E) -> bool {
+        match self.take() {
+            Some(complete) => {
+                complete.fail(err);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns true if the latch has been resolved or failed.
+    pub fn is_done(&self) -> bool {
+        self.inner.done.load(Ordering::Acquire)
+    }
+
+    fn take(&self) -> Option<Complete<V, E>> {
+        if self.inner.done.swap(true, Ordering::AcqRel) {
+            return None;
+        }
+
+        self.inner.complete.lock().unwrap().take()
+    }
+}
+
+impl<V: Send + 'static, E: Send + 'static> Clone for Latch<V, E> {
+    fn clone(&self) -> Latch<V, E> {
+        Latch { inner: self.inner.clone() }
+    }
+}
+
+impl<V: Send + 'static, E: Send + 'static> fmt::Debug for Latch<V, E> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "Latch {{ done: {} }}", self.is_done())
+    }
+}
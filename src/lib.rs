@@ -55,17 +55,29 @@ extern crate time;
 #[macro_use]
 extern crate log;
 
+pub use self::broadcast::{broadcast, broadcast_lagged, BroadcastSender, Subscriber, Lagged};
+pub use self::cache::cache;
+pub use self::context::{ContextualAsync, ContextualCancel};
+pub use self::erased::{BoxedAsync, ErasedAsync, ErasedCancel};
 pub use self::future::{Future, Complete};
-pub use self::join::{join, Join};
+pub use self::join::{join, join_all, join_with_limit, Join};
+pub use self::latch::Latch;
+pub use self::notify::{Notify, Notifier, Notification};
 pub use self::receipt::Receipt;
-pub use self::run::{background, defer};
-pub use self::select::{select, Select};
-pub use self::sequence::sequence;
-pub use self::stream::{Stream, StreamIter, Sender, BusySender};
-pub use self::timer::Timer;
+pub use self::retry::{retry, retry_with_backoff, Backoff};
+pub use self::run::{background, background_catching, defer, defer_with, ThreadPool};
+pub use self::select::{select, select_any, select_ok, select_stream, Select};
+pub use self::semaphore::{Semaphore, Permit, SemaphoreError};
+pub use self::sequence::{par_sequence, sequence, sequence_n, sequence_n_ordered, sequence_stream};
+pub use self::shared::{SharedFuture, SharedReceipt};
+pub use self::shared_sender::SharedSender;
+pub use self::stream::{channel, Stream, StreamIter, PeekableStream, Sender, BusySender};
+pub use self::sync::{AsyncMutex, AsyncMutexGuard};
+pub use self::timer::{sleep, Timer, TimedOut, TimerError};
 
 use std::error::Error;
 use std::fmt;
+use time::Duration;
 
 // ## TODO
 //
@@ -76,15 +88,28 @@ use std::fmt;
 //
 // * Improve performance / reduce allocations
 
+mod broadcast;
+mod buffered;
+mod cache;
+mod context;
 mod core;
+mod erased;
 mod future;
 mod join;
+mod latch;
+mod notify;
 mod process;
 mod receipt;
+mod retry;
 mod run;
 mod select;
+mod semaphore;
 mod sequence;
+mod shared;
+mod shared_sender;
+mod split;
 mod stream;
+mod sync;
 mod timer;
 
 /// A value representing an asynchronous computation
@@ -137,6 +162,95 @@ pub trait Async : Send + 'static + Sized {
         rx.recv().ok().expect("async disappeared without a trace")
     }
 
+    /// Blocks the thread for at most `dur` waiting for the async value to
+    /// complete.
+    ///
+    /// Returns the result if the async value completes in time, otherwise
+    /// returns the original async value, which can be waited on again or
+    /// dropped to cancel the computation.
+    ///
+    /// Async values that are unable to cancel a registered callback, such as
+    /// `BusySender`, are waited on until they complete.
+    ///
+    /// ```
+    /// # extern crate eventual;
+    /// # extern crate time;
+    /// use eventual::*;
+    /// use time::Duration;
+    /// # fn main() {
+    ///
+    /// let (c, f) = Future::<i32, ()>::pair();
+    ///
+    /// let f = f.wait_timeout(Duration::milliseconds(10)).unwrap_err();
+    /// c.complete(123);
+    ///
+    /// assert_eq!(Ok(123), f.wait_timeout(Duration::milliseconds(10)).unwrap());
+    /// # }
+    /// ```
+    fn wait_timeout(self, dur: Duration) -> Result<AsyncResult<Self::Value, Self::Error>, Self> {
+        use std::sync::mpsc::channel;
+        use std::time::Duration as StdDuration;
+
+        let (tx, rx) = channel();
+        let cancel = self.ready(move |async| drop(tx.send(async)));
+        let timeout = dur.to_std().unwrap_or(StdDuration::from_millis(0));
+
+        let async = match rx.recv_timeout(timeout) {
+            Ok(async) => async,
+            Err(_) => {
+                match cancel.cancel() {
+                    // The deadline elapsed, hand back the async value
+                    Some(async) => return Err(async),
+                    // The callback has fired or is about to
+                    None => rx.recv().ok().expect("async disappeared without a trace"),
+                }
+            }
+        };
+
+        match async.poll() {
+            Ok(res) => Ok(res),
+            Err(_) => panic!("ready callback invoked but is not actually ready"),
+        }
+    }
+
+    /// Returns the result if the async value is complete, `None` otherwise.
+    /// Never blocks the thread.
+    ///
+    /// If the value is not yet complete, it is dropped and the computation is
+    /// canceled. Use `poll` to get the async value back instead.
+    fn try_await(self) -> Option<AsyncResult<Self::Value, Self::Error>> {
+        self.poll().ok()
+    }
+
+    /// Blocks the thread until the async value is complete and returns its
+    /// value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the async value fails or is aborted.
+    fn unwrap(self) -> Self::Value
+            where Self::Error: fmt::Debug {
+        match self.await() {
+            Ok(v) => v,
+            Err(e) => panic!("called `Async::unwrap()` on an error: {:?}", e),
+        }
+    }
+
+    /// Blocks the thread until the async value is complete and returns its
+    /// error.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the async value succeeds or is aborted.
+    fn unwrap_err(self) -> Self::Error
+            where Self::Value: fmt::Debug {
+        match self.await() {
+            Ok(v) => panic!("called `Async::unwrap_err()` on a value: {:?}", v),
+            Err(AsyncError::Failed(e)) => e,
+            Err(AsyncError::Aborted) => panic!("called `Async::unwrap_err()` on an aborted value"),
+        }
+    }
+
     /// Trigger the computation without waiting for the result
     fn fire(self) {
         self.receive(drop)
@@ -160,6 +274,46 @@ pub trait Async : Send + 'static + Sized {
         self.and_then(move |_| next)
     }
 
+    /// This method returns a future whose completion value depends on the
+    /// completion value of the original future.
+    ///
+    /// If the original future completes with an error, the future returned by
+    /// this method completes with that error.
+    ///
+    /// If the original future completes successfully, its value is discarded
+    /// and the future returned by this method completes with `val`.
+    ///
+    /// ```
+    /// use eventual::*;
+    ///
+    /// let f = Future::<i32, ()>::of(1).and_return("done");
+    /// assert_eq!(Ok("done"), f.await());
+    /// ```
+    fn and_return<U: Send + 'static>(self, val: U) -> Future<U, Self::Error> {
+        self.map(move |_| val)
+    }
+
+    /// This method returns a future that completes with the completion value
+    /// of the original future, but only once `guard` has completed.
+    ///
+    /// If `guard` completes with an error, the future returned by this method
+    /// completes with that error and the original future is dropped.
+    ///
+    /// ```
+    /// use eventual::*;
+    ///
+    /// let (open, guard) = Future::<(), ()>::pair();
+    /// let gated = Future::of(1).gate(guard);
+    ///
+    /// assert!(!gated.is_ready());
+    ///
+    /// open.complete(());
+    /// assert_eq!(Ok(1), gated.await());
+    /// ```
+    fn gate<G: Async<Error=Self::Error>>(self, guard: G) -> Future<Self::Value, Self::Error> {
+        guard.and(self)
+    }
+
     /// This method returns a future whose completion value depends on the
     /// completion value of the original future.
     ///
@@ -270,6 +424,572 @@ pub trait Async : Send + 'static + Sized {
 
         ret
     }
+
+    /// This method returns a future whose completion value depends on the
+    /// completion value of the original future.
+    ///
+    /// Once the original future completes, successfully or not, the callback
+    /// is invoked with its result and returns a new future. The future
+    /// returned by this method then completes with the completion value of
+    /// that returned future.
+    ///
+    /// ```
+    /// use eventual::*;
+    ///
+    /// let f = Future::<i32, &'static str>::error("failed");
+    ///
+    /// let res = f.then(|res| {
+    ///     match res {
+    ///         Ok(v) => Ok::<i32, ()>(v),
+    ///         Err(_) => Ok(0),
+    ///     }
+    /// }).await();
+    ///
+    /// assert_eq!(Ok(0), res);
+    /// ```
+    fn then<F, U: Async>(self, f: F) -> Future<U::Value, U::Error>
+            where F: FnOnce(AsyncResult<Self::Value, Self::Error>) -> U + Send + 'static {
+        let (complete, ret) = Future::pair();
+
+        complete.receive(move |c| {
+            if let Ok(complete) = c {
+                self.receive(move |res| {
+                    f(res).receive(move |res| {
+                        match res {
+                            Ok(u) => complete.complete(u),
+                            Err(AsyncError::Failed(e)) => complete.fail(e),
+                            Err(AsyncError::Aborted) => drop(complete),
+                        }
+                    });
+                });
+            }
+        });
+
+        ret
+    }
+
+    /// This method is the same as `then`, except that the callback is
+    /// invoked on a newly spawned thread instead of on the thread that
+    /// completes the original future. This is useful when the callback
+    /// performs expensive work.
+    ///
+    /// ```
+    /// use eventual::*;
+    ///
+    /// let f = Future::<i32, ()>::of(21);
+    ///
+    /// let res = f.then_spawn(|res| {
+    ///     // Represents an expensive computation
+    ///     Ok::<i32, ()>(res.unwrap() * 2)
+    /// }).await();
+    ///
+    /// assert_eq!(Ok(42), res);
+    /// ```
+    fn then_spawn<F, U: Async>(self, f: F) -> Future<U::Value, U::Error>
+            where F: FnOnce(AsyncResult<Self::Value, Self::Error>) -> U + Send + 'static {
+        use future::{run_forward, ThreadSpawner};
+
+        let (complete, ret) = Future::pair();
+
+        complete.receive(move |c| {
+            if let Ok(complete) = c {
+                self.receive(move |res| {
+                    run_forward(&ThreadSpawner, complete, move || f(res));
+                });
+            }
+        });
+
+        ret
+    }
+
+    /// This method is the same as `and_then`, except that the callback is
+    /// invoked by the given task runner, such as a `ThreadPool`, rather than
+    /// by whichever thread completed the original future.
+    ///
+    /// This keeps CPU heavy follow-up work off of the thread that resolves
+    /// the original future.
+    ///
+    /// ```
+    /// use eventual::*;
+    ///
+    /// let pool = ThreadPool::new(1);
+    ///
+    /// let res = Future::<i32, ()>::of(21)
+    ///     .and_then_spawn(|v| Ok(v * 2), pool)
+    ///     .await();
+    ///
+    /// assert_eq!(Ok(42), res);
+    /// ```
+    fn and_then_spawn<F, U, R>(self, f: F, task_runner: R) -> Future<U::Value, Self::Error>
+            where F: FnOnce(Self::Value) -> U + Send + 'static,
+                  U: Async<Error=Self::Error>,
+                  R: syncbox::Run<Box<syncbox::TaskBox>> + Send + 'static {
+        use future::run_forward;
+
+        let (complete, ret) = Future::pair();
+
+        complete.receive(move |c| {
+            if let Ok(complete) = c {
+                self.receive(move |res| {
+                    match res {
+                        Ok(v) => run_forward(&task_runner, complete, move || f(v)),
+                        Err(AsyncError::Failed(e)) => complete.fail(e),
+                        Err(AsyncError::Aborted) => drop(complete),
+                    }
+                });
+            }
+        });
+
+        ret
+    }
+
+    /// This method returns a future whose completion value is the result of
+    /// applying the given function to the completion value of the original
+    /// future.
+    ///
+    /// If the original future completes with an error, the future returned by
+    /// this method completes with that error.
+    ///
+    /// If the original future is already complete, the function is applied
+    /// immediately.
+    ///
+    /// ```
+    /// use eventual::*;
+    ///
+    /// let f = Future::<i32, ()>::spawn(|| 21);
+    /// assert_eq!(42, f.map(|v| v * 2).await().unwrap());
+    /// ```
+    fn map<F, U>(self, f: F) -> Future<U, Self::Error>
+            where F: FnOnce(Self::Value) -> U + Send + 'static,
+                  U: Send + 'static {
+
+        let async = match self.poll() {
+            Ok(Ok(v)) => return Future::of(f(v)),
+            Ok(Err(AsyncError::Failed(e))) => return Future::error(e),
+            Ok(Err(AsyncError::Aborted)) => {
+                let (complete, ret) = Future::pair();
+                complete.abort();
+                return ret;
+            }
+            Err(async) => async,
+        };
+
+        let (complete, ret) = Future::pair();
+
+        complete.receive(move |res| {
+            if let Ok(complete) = res {
+                async.receive(move |res| {
+                    match res {
+                        Ok(v) => complete.complete(f(v)),
+                        Err(AsyncError::Failed(e)) => complete.fail(e),
+                        Err(AsyncError::Aborted) => drop(complete),
+                    }
+                });
+            }
+        });
+
+        ret
+    }
+
+    /// This method returns a future whose completion value is the completion
+    /// value of the original future converted with `From`.
+    ///
+    /// ```
+    /// use eventual::*;
+    ///
+    /// let f = Future::<u8, ()>::of(42);
+    /// assert_eq!(Ok(42u32), f.value_into().await());
+    /// ```
+    fn value_into<U>(self) -> Future<U, Self::Error>
+            where U: From<Self::Value> + Send + 'static {
+        self.map(From::from)
+    }
+
+    /// This method returns a future whose completion value depends on the
+    /// completion value of the original future.
+    ///
+    /// If the original future completes successfully, the future returned by
+    /// this method completes with that value and the callback is not invoked.
+    ///
+    /// If the original future completes with an error, the callback is
+    /// invoked with the error and the future returned by this method fails
+    /// with the value returned by the callback.
+    ///
+    /// ```
+    /// use eventual::*;
+    ///
+    /// fn describe<A: Async<Error=i32>>(async: A) -> Future<A::Value, String> {
+    ///     async.map_err(|code| format!("error code {}", code))
+    /// }
+    ///
+    /// let res = describe(Future::<(), i32>::error(123)).await();
+    /// assert_eq!(Err(AsyncError::Failed("error code 123".to_string())), res);
+    /// ```
+    fn map_err<F, U>(self, f: F) -> Future<Self::Value, U>
+            where F: FnOnce(Self::Error) -> U + Send + 'static,
+                  U: Send + 'static {
+        let (complete, ret) = Future::pair();
+
+        complete.receive(move |res| {
+            if let Ok(complete) = res {
+                self.receive(move |res| {
+                    match res {
+                        Ok(v) => complete.complete(v),
+                        Err(AsyncError::Failed(e)) => complete.fail(f(e)),
+                        Err(AsyncError::Aborted) => drop(complete),
+                    }
+                });
+            }
+        });
+
+        ret
+    }
+
+    /// This method returns a future whose completion value depends on the
+    /// completion value of the original future.
+    ///
+    /// If the original future completes successfully, the future returned by
+    /// this method completes with that value and the callback is not invoked.
+    ///
+    /// If the original future completes with an error, the callback is
+    /// invoked with the error and the future returned by this method
+    /// completes successfully with the value returned by the callback. This
+    /// is the synchronous counterpart of `or_else`.
+    ///
+    /// ```
+    /// use eventual::*;
+    ///
+    /// let res = Future::<i32, &'static str>::error("nope").recover(|_| 0).await();
+    /// assert_eq!(Ok(0), res);
+    /// ```
+    fn recover<F>(self, f: F) -> Future<Self::Value, Self::Error>
+            where F: FnOnce(Self::Error) -> Self::Value + Send + 'static {
+        let (complete, ret) = Future::pair();
+
+        complete.receive(move |res| {
+            if let Ok(complete) = res {
+                self.receive(move |res| {
+                    match res {
+                        Ok(v) => complete.complete(v),
+                        Err(AsyncError::Failed(e)) => complete.complete(f(e)),
+                        Err(AsyncError::Aborted) => drop(complete),
+                    }
+                });
+            }
+        });
+
+        ret
+    }
+
+    /// This method returns a future that unwraps a `Result` completion value
+    /// of the original future.
+    ///
+    /// If the original future completes with `Ok`, the future returned by
+    /// this method completes with the inner value. If it completes with
+    /// `Err`, the future returned by this method fails with the inner error.
+    ///
+    /// ```
+    /// use eventual::*;
+    ///
+    /// let f = Future::<Result<i32, &'static str>, &'static str>::of(Err("invalid"));
+    /// assert_eq!(Err(AsyncError::Failed("invalid")), f.flatten_result().await());
+    /// ```
+    fn flatten_result<V>(self) -> Future<V, Self::Error>
+            where Self: Async<Value=Result<V, <Self as Async>::Error>>,
+                  V: Send + 'static {
+        self.and_then(|res| res)
+    }
+
+    /// This method returns a future that completes with the same value as the
+    /// original future, after invoking the callback with a reference to it.
+    ///
+    /// If the original future fails, the callback is not invoked and the
+    /// error is passed through untouched.
+    ///
+    /// ```
+    /// use eventual::*;
+    ///
+    /// let res = Future::<i32, ()>::of(123)
+    ///     .inspect(|v| println!("got {}", v))
+    ///     .map(|v| v + 1)
+    ///     .await();
+    ///
+    /// assert_eq!(Ok(124), res);
+    /// ```
+    fn inspect<F>(self, f: F) -> Future<Self::Value, Self::Error>
+            where F: FnOnce(&Self::Value) + Send + 'static {
+        self.map(move |v| {
+            f(&v);
+            v
+        })
+    }
+
+    /// This method returns a future that completes with the same value or
+    /// error as the original future, after invoking the callback with a
+    /// reference to the error if the original future fails.
+    ///
+    /// If the original future succeeds or is aborted, the callback is not
+    /// invoked.
+    ///
+    /// ```
+    /// use eventual::*;
+    ///
+    /// let res = Future::<i32, &'static str>::error("nope")
+    ///     .tap_err(|e| println!("failed: {}", e))
+    ///     .await();
+    ///
+    /// assert_eq!(Err(AsyncError::failed("nope")), res);
+    /// ```
+    fn tap_err<F>(self, f: F) -> Future<Self::Value, Self::Error>
+            where F: FnOnce(&Self::Error) + Send + 'static {
+        self.map_err(move |e| {
+            f(&e);
+            e
+        })
+    }
+
+    /// This method is the same as `tap_err`, except that the callback is also
+    /// invoked, with a reference to `aborted`, if the original future is
+    /// aborted. The returned future is still aborted in that case.
+    ///
+    /// ```
+    /// use eventual::*;
+    ///
+    /// let (c, f) = Future::<i32, &'static str>::pair();
+    /// drop(c);
+    ///
+    /// let res = f
+    ///     .tap_err_or_abort("aborted", |e| println!("failed: {}", e))
+    ///     .await();
+    ///
+    /// assert_eq!(Err(AsyncError::aborted()), res);
+    /// ```
+    fn tap_err_or_abort<F>(self, aborted: Self::Error, f: F) -> Future<Self::Value, Self::Error>
+            where F: FnOnce(&Self::Error) + Send + 'static {
+
+        let (complete, ret) = Future::pair();
+
+        complete.receive(move |c| {
+            if let Ok(complete) = c {
+                self.receive(move |res| {
+                    match res {
+                        Ok(v) => complete.complete(v),
+                        Err(AsyncError::Failed(e)) => {
+                            f(&e);
+                            complete.fail(e);
+                        }
+                        Err(AsyncError::Aborted) => {
+                            f(&aborted);
+                            drop(complete);
+                        }
+                    }
+                });
+            }
+        });
+
+        ret
+    }
+
+    /// This method returns a future that completes with the values of both
+    /// the original future and `other`, which are computed concurrently.
+    ///
+    /// If either future completes with an error, the future returned by this
+    /// method completes with that error and the other future is canceled.
+    ///
+    /// ```
+    /// use eventual::*;
+    ///
+    /// let a = Future::<i32, ()>::spawn(|| 1);
+    /// let b = Future::<&'static str, ()>::spawn(|| "two");
+    ///
+    /// assert_eq!((1, "two"), a.zip(b).await().unwrap());
+    /// ```
+    fn zip<U: Async<Error=Self::Error>>(self, other: U) -> Future<(Self::Value, U::Value), Self::Error> {
+        select((self, other))
+            .and_then(|(i, (a, b))| {
+                // The selected value has completed successfully, wait for the
+                // other one.
+                if i == 0 {
+                    let v1 = a.expect().ok().expect("selected value not successful");
+                    b.and_then(move |v2| Ok((v1, v2)))
+                } else {
+                    let v2 = b.expect().ok().expect("selected value not successful");
+                    a.and_then(move |v1| Ok((v1, v2)))
+                }
+            })
+    }
+
+    /// This method returns a future that completes with the value or error
+    /// of whichever of the original future and `other` completes first. The
+    /// other one is canceled.
+    ///
+    /// If both are already complete when the race starts, the original
+    /// future wins.
+    ///
+    /// ```
+    /// use eventual::*;
+    ///
+    /// let (c, slow) = Future::<i32, ()>::pair();
+    /// let fast = Future::of(2);
+    ///
+    /// assert_eq!(Ok(2), slow.race(fast).await());
+    /// assert!(c.is_err());
+    /// ```
+    fn race<U>(self, other: U) -> Future<Self::Value, Self::Error>
+            where U: Async<Value=Self::Value, Error=Self::Error> {
+
+        select((self, other))
+            .and_then(|(i, (a, b))| {
+                // Dropping the async value that lost the race cancels it
+                if i == 0 {
+                    drop(b);
+                    Ok(a.expect().ok().expect("selected value not successful"))
+                } else {
+                    drop(a);
+                    Ok(b.expect().ok().expect("selected value not successful"))
+                }
+            })
+    }
+
+    /// This method returns a future that completes with the value or error
+    /// of whichever of the original future and `other` completes first,
+    /// with the value wrapped in `Either::Left` or `Either::Right`
+    /// respectively. The other one is canceled.
+    ///
+    /// This is `race` for async values of different types. If both are
+    /// already complete, the original future wins.
+    ///
+    /// ```
+    /// use eventual::*;
+    ///
+    /// let (c, slow) = Future::<i32, ()>::pair();
+    /// let fast = Future::of("fast");
+    ///
+    /// assert_eq!(Ok(Either::Right("fast")), slow.either(fast).await());
+    /// assert!(c.is_err());
+    /// ```
+    fn either<U>(self, other: U) -> Future<Either<Self::Value, U::Value>, Self::Error>
+            where U: Async<Error=Self::Error> {
+
+        select((self, other))
+            .and_then(|(i, (a, b))| {
+                // Dropping the async value that lost the race cancels it
+                if i == 0 {
+                    drop(b);
+                    Ok(Either::Left(a.expect().ok().expect("selected value not successful")))
+                } else {
+                    drop(a);
+                    Ok(Either::Right(b.expect().ok().expect("selected value not successful")))
+                }
+            })
+    }
+
+    /// This method returns a future whose completion value depends on the
+    /// completion value of the original future, provided that it completes
+    /// within `dur`.
+    ///
+    /// If the original future completes before the deadline, the future
+    /// returned by this method completes with that value or error and the
+    /// deadline is canceled.
+    ///
+    /// If the deadline is reached first, the original future is canceled and
+    /// the future returned by this method fails with `TimedOut`.
+    ///
+    /// ```
+    /// extern crate eventual;
+    /// extern crate time;
+    ///
+    /// use eventual::*;
+    /// use time::Duration;
+    ///
+    /// # fn main() {
+    /// let (_complete, future) = Future::<i32, TimedOut>::pair();
+    ///
+    /// let res = future.timeout(Duration::milliseconds(50)).await();
+    /// assert_eq!(Err(AsyncError::Failed(TimedOut)), res);
+    /// # }
+    /// ```
+    fn timeout(self, dur: Duration) -> Future<Self::Value, Self::Error>
+            where Self::Error: From<TimedOut> {
+
+        let deadline = timer::shared()
+            .timeout_ms(timer::to_ms(dur))
+            .map_err(|_| unreachable!());
+
+        select((self, deadline))
+            .and_then(|(i, (async, deadline))| {
+                // Dropping the async value that lost the race cancels it
+                drop(deadline);
+
+                if i == 0 {
+                    // Select only succeeds once the winner has completed
+                    // successfully
+                    Ok(async.expect().ok().expect("selected value not successful"))
+                } else {
+                    Err(From::from(TimedOut))
+                }
+            })
+    }
+
+    /// This method returns the original async value wrapped along with a
+    /// context value, such as a trace context, that callbacks registered
+    /// further down the computation chain have access to. See
+    /// `ContextualAsync`.
+    ///
+    /// ```
+    /// use eventual::*;
+    ///
+    /// let f = Future::<i32, ()>::of(1).with_context("trace-1")
+    ///     .map_ctx(|ctx, v| (ctx.to_string(), v + 1));
+    ///
+    /// assert_eq!(Ok(("trace-1".to_string(), 2)), f.await());
+    /// ```
+    fn with_context<C: Clone + Send + 'static>(self, ctx: C) -> ContextualAsync<Self, C> {
+        context::new(self, ctx)
+    }
+
+    /// This method returns the original async value with its concrete type
+    /// erased, allowing async values of different types that share a value
+    /// and error type to be stored together.
+    ///
+    /// ```
+    /// use eventual::*;
+    ///
+    /// let asyncs = vec![
+    ///     Future::<i32, ()>::of(1).erase(),
+    ///     Ok::<i32, ()>(2).erase(),
+    /// ];
+    ///
+    /// assert_eq!(Ok(vec![1, 2]), join(asyncs).await());
+    /// ```
+    fn erase(self) -> ErasedAsync<Self::Value, Self::Error> {
+        erased::new(self)
+    }
+
+    /// This method returns the original async value boxed behind a trait
+    /// object. This is the same as `erase`.
+    fn boxed(self) -> BoxedAsync<Self::Value, Self::Error> {
+        self.erase()
+    }
+}
+
+/// The producer half of a `Future`, under the name used by other futures
+/// libraries. See `Complete`.
+pub type Promise<T, E> = Complete<T, E>;
+
+/// Returns a new `Promise` along with the `Future` it completes. This is the
+/// same as `Future::pair`.
+///
+/// ```
+/// use eventual::*;
+///
+/// let (p, f) = promise::<i32, ()>();
+/// p.complete(123);
+///
+/// assert_eq!(Ok(123), f.await());
+/// ```
+pub fn promise<T: Send + 'static, E: Send + 'static>() -> (Promise<T, E>, Future<T, E>) {
+    Future::pair()
 }
 
 pub trait Pair {
@@ -357,7 +1077,10 @@ impl Async for () {
 
 pub type AsyncResult<T, E> = Result<T, AsyncError<E>>;
 
-#[derive(Eq, PartialEq)]
+/// A type-erased error, see `Complete::fail_with`.
+pub type BoxedError = Box<Error + Send + Sync + 'static>;
+
+#[derive(Clone, Eq, PartialEq)]
 pub enum AsyncError<E: Send + 'static> {
     Failed(E),
     Aborted,
@@ -399,6 +1122,38 @@ impl<E: Send + 'static> AsyncError<E> {
             _ => None,
         }
     }
+
+    /// Maps a `Failed` error to another error type by applying the function
+    /// to the contained error. `Aborted` is left untouched.
+    pub fn map<F, E2>(self, f: F) -> AsyncError<E2>
+            where F: FnOnce(E) -> E2,
+                  E2: Send + 'static {
+        match self {
+            AsyncError::Failed(err) => AsyncError::Failed(f(err)),
+            AsyncError::Aborted => AsyncError::Aborted,
+        }
+    }
+
+    /// Maps a `Failed` error to the `AsyncError` returned by the function,
+    /// which may turn the failure into an abort. `Aborted` is left untouched.
+    pub fn and_then<F, E2>(self, f: F) -> AsyncError<E2>
+            where F: FnOnce(E) -> AsyncError<E2>,
+                  E2: Send + 'static {
+        match self {
+            AsyncError::Failed(err) => f(err),
+            AsyncError::Aborted => AsyncError::Aborted,
+        }
+    }
+}
+
+// Does not overlap with the reflexive `impl<T> From<T> for T`, since
+// `AsyncError<E>` can never be the same type as `E`.
+impl<E: Send + 'static> From<E> for AsyncError<E> {
+    /// Wraps the error in `AsyncError::Failed`, allowing `?` to be used on
+    /// `Result<T, E>` in functions returning `AsyncResult<T, E>`.
+    fn from(err: E) -> AsyncError<E> {
+        AsyncError::Failed(err)
+    }
 }
 
 impl<E: Send + Error + 'static> Error for AsyncError<E> {
@@ -435,6 +1190,47 @@ impl<E: Send + 'static + fmt::Display> fmt::Display for AsyncError<E> {
     }
 }
 
+/*
+ *
+ * ===== Either =====
+ *
+ */
+
+/// A value that is one of two types, used when combining async values of
+/// different types, such as by `Stream::merge`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Either<L, R> {
+    Left(L),
+    Right(R),
+}
+
+impl<L, R> Either<L, R> {
+    pub fn is_left(&self) -> bool {
+        match *self {
+            Either::Left(_) => true,
+            Either::Right(_) => false,
+        }
+    }
+
+    pub fn is_right(&self) -> bool {
+        !self.is_left()
+    }
+
+    pub fn left(self) -> Option<L> {
+        match self {
+            Either::Left(v) => Some(v),
+            Either::Right(_) => None,
+        }
+    }
+
+    pub fn right(self) -> Option<R> {
+        match self {
+            Either::Left(_) => None,
+            Either::Right(v) => Some(v),
+        }
+    }
+}
+
 /*
  *
  * ===== BoxedReceive =====
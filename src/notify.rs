@@ -0,0 +1,179 @@
+use {Future, Complete};
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use std::fmt;
+
+/// A one-shot async semaphore, for waking up async code waiting on a
+/// condition.
+///
+/// `Notify::new` returns a `Notifier` along with a `Notification`. Calling
+/// `Notifier::notify` wakes up a pending `Notification::wait`, or the next
+/// one if none is pending. Notifications are level triggered: notifying
+/// several times before anybody waits results in a single wakeup.
+///
+/// ```
+/// use eventual::*;
+///
+/// let (notifier, notification) = Notify::new();
+///
+/// notifier.notify();
+/// notifier.notify();
+///
+/// assert_eq!(Ok(()), notification.wait().await());
+/// assert!(!notification.wait().is_ready());
+/// ```
+pub struct Notify {
+    _priv: (),
+}
+
+impl Notify {
+    /// Returns a new `Notifier` along with the `Notification` it wakes up.
+    pub fn new() -> (Notifier, Notification) {
+        let inner = Arc::new(Mutex::new(Inner {
+            notified: false,
+            waiters: VecDeque::new(),
+            notifiers: 1,
+        }));
+
+        (Notifier { inner: inner.clone() }, Notification { inner: inner })
+    }
+}
+
+impl fmt::Debug for Notify {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "Notify")
+    }
+}
+
+/*
+ *
+ * ===== Notifier =====
+ *
+ */
+
+/// Wakes up the associated `Notification`. Clones of a notifier wake up the
+/// same notification.
+pub struct Notifier {
+    inner: Arc<Mutex<Inner>>,
+}
+
+impl Notifier {
+    /// Wakes up the oldest pending wait. If there is none, the next wait
+    /// completes right away.
+    pub fn notify(&self) {
+        let waiter = {
+            let mut inner = self.inner.lock().unwrap();
+            let waiter = inner.next_waiter();
+
+            if waiter.is_none() {
+                inner.notified = true;
+            }
+
+            waiter
+        };
+
+        if let Some(complete) = waiter {
+            complete.complete(());
+        }
+    }
+}
+
+impl Clone for Notifier {
+    fn clone(&self) -> Notifier {
+        self.inner.lock().unwrap().notifiers += 1;
+        Notifier { inner: self.inner.clone() }
+    }
+}
+
+impl Drop for Notifier {
+    fn drop(&mut self) {
+        let waiters: Vec<_> = {
+            let mut inner = self.inner.lock().unwrap();
+            inner.notifiers -= 1;
+
+            if inner.notifiers > 0 {
+                return;
+            }
+
+            inner.waiters.drain(..).collect()
+        };
+
+        // Nobody is left to wake up the pending waits
+        for complete in waiters {
+            complete.fail(());
+        }
+    }
+}
+
+impl fmt::Debug for Notifier {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "Notifier {{ ... }}")
+    }
+}
+
+/*
+ *
+ * ===== Notification =====
+ *
+ */
+
+/// Waits on notifications sent by the associated `Notifier`.
+pub struct Notification {
+    inner: Arc<Mutex<Inner>>,
+}
+
+impl Notification {
+    /// Returns a future that completes once a notification is received,
+    /// consuming it. The future fails if every `Notifier` is dropped first.
+    ///
+    /// Pending waits are woken up in the order they were made. Dropping the
+    /// future before it completes gives up its place in the queue.
+    pub fn wait(&self) -> Future<(), ()> {
+        let mut inner = self.inner.lock().unwrap();
+
+        if inner.notified {
+            inner.notified = false;
+            return Future::of(());
+        }
+
+        if inner.notifiers == 0 {
+            return Future::error(());
+        }
+
+        let (complete, ret) = Future::pair();
+        inner.waiters.push_back(complete);
+        ret
+    }
+}
+
+impl fmt::Debug for Notification {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "Notification {{ notified: {} }}", self.inner.lock().unwrap().notified)
+    }
+}
+
+/*
+ *
+ * ===== Internals =====
+ *
+ */
+
+struct Inner {
+    // Set when notified while nobody was waiting
+    notified: bool,
+    waiters: VecDeque<Complete<(), ()>>,
+    notifiers: usize,
+}
+
+impl Inner {
+    // Returns the first waiter that is still interested in a notification
+    fn next_waiter(&mut self) -> Option<Complete<(), ()>> {
+        while let Some(complete) = self.waiters.pop_front() {
+            if !complete.is_cancelled() {
+                return Some(complete);
+            }
+        }
+
+        None
+    }
+}
@@ -0,0 +1,127 @@
+use {timer, Async, AsyncError, Future, Complete};
+use time::Duration;
+use std::cmp;
+
+/// Returns a `Future` representing the first successful completion of the
+/// async value returned by `f`, invoking `f` again each time the value fails,
+/// up to `attempts` invocations in total.
+///
+/// If every attempt fails, the returned future fails with the error of the
+/// last attempt. No further attempts are made once the returned future is
+/// dropped.
+///
+/// ```
+/// use eventual::*;
+/// use std::sync::Arc;
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+///
+/// let count = Arc::new(AtomicUsize::new(0));
+/// let c = count.clone();
+///
+/// let res = retry(move || {
+///     if c.fetch_add(1, Ordering::Relaxed) < 2 {
+///         Err("transient")
+///     } else {
+///         Ok("success")
+///     }
+/// }, 5).await();
+///
+/// assert_eq!(Ok("success"), res);
+/// assert_eq!(3, count.load(Ordering::Relaxed));
+/// ```
+pub fn retry<F, A>(f: F, attempts: usize) -> Future<A::Value, A::Error>
+        where F: Fn() -> A + Send + 'static,
+              A: Async {
+    retry_with_backoff(f, attempts, Backoff::Immediate)
+}
+
+/// Same as `retry`, but waits between attempts according to `backoff`.
+///
+/// # Panics
+///
+/// Panics if `attempts` is 0.
+pub fn retry_with_backoff<F, A>(f: F, attempts: usize, backoff: Backoff) -> Future<A::Value, A::Error>
+        where F: Fn() -> A + Send + 'static,
+              A: Async {
+
+    assert!(attempts > 0, "attempts must be greater than 0");
+
+    let (complete, ret) = Future::pair();
+
+    // Don't do any work until the consumer registers interest in the completed
+    // value.
+    complete.receive(move |res| {
+        if let Ok(complete) = res {
+            attempt(f, 1, attempts, backoff, complete);
+        }
+    });
+
+    ret
+}
+
+fn attempt<F, A>(f: F, n: usize, attempts: usize, backoff: Backoff, complete: Complete<A::Value, A::Error>)
+        where F: Fn() -> A + Send + 'static,
+              A: Async {
+
+    debug!("retry attempt; n={}; attempts={}", n, attempts);
+
+    f().receive(move |res| {
+        match res {
+            Ok(v) => complete.complete(v),
+            Err(AsyncError::Failed(e)) => {
+                // Give up if this was the last attempt or nobody is
+                // interested in the result anymore
+                if n == attempts || complete.is_err() {
+                    complete.fail(e);
+                    return;
+                }
+
+                let delay = backoff.delay(n);
+
+                if delay <= Duration::zero() {
+                    attempt(f, n + 1, attempts, backoff, complete);
+                    return;
+                }
+
+                timer::shared().timeout_ms(timer::to_ms(delay)).receive(move |_| {
+                    attempt(f, n + 1, attempts, backoff, complete);
+                });
+            }
+            Err(AsyncError::Aborted) => drop(complete),
+        }
+    });
+}
+
+/*
+ *
+ * ===== Backoff =====
+ *
+ */
+
+/// How long `retry_with_backoff` waits before each retry.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Backoff {
+    /// Retry right away.
+    Immediate,
+    /// Wait `n` times the duration before the `n`th retry.
+    Linear(Duration),
+    /// Wait the duration before the first retry, doubling it for each
+    /// subsequent retry.
+    Exponential(Duration),
+}
+
+impl Backoff {
+    /// Returns the time to wait before the `n`th retry, starting at 1.
+    pub fn delay(&self, n: usize) -> Duration {
+        match *self {
+            Backoff::Immediate => Duration::zero(),
+            Backoff::Linear(d) => scale(d, n as i64),
+            Backoff::Exponential(d) => scale(d, 1 << cmp::min(n.saturating_sub(1), 62)),
+        }
+    }
+}
+
+// Millisecond resolution is all the timer supports
+fn scale(dur: Duration, factor: i64) -> Duration {
+    Duration::milliseconds(dur.num_milliseconds().saturating_mul(factor))
+}
@@ -1,7 +1,9 @@
 use super::{Async, Pair, AsyncError, Future};
+use syncbox::{self, LinkedQueue};
 use syncbox::Task;
 use syncbox::TaskBox;
 use syncbox::Run;
+use std::any::Any;
 
 /// This method defers a task onto a task runner until we can complete that call.
 /// Currently we only support using a ThreadPool as the task runner itself.
@@ -33,3 +35,99 @@ pub fn background<R: Run<Box<TaskBox>> + Send + 'static, F: FnOnce() -> T  + Sen
     complete.complete(());
     res
 }
+
+/// This method backgrounds a task onto a task runner, like `background`, but
+/// catches a panic of the closure instead of letting it unwind the task
+/// runner's thread. The returned future fails with the panic payload.
+pub fn background_catching<R, F, V>(task_runner: R, f: F) -> Future<V, Box<Any + Send>>
+        where R: Run<Box<TaskBox>> + Send + 'static,
+              F: FnOnce() -> V + Send + 'static,
+              V: Send + 'static {
+    let (complete, future) = Future::panic_aware();
+
+    // Like `defer`, wait until the consumer is interested in the value
+    complete.receive(move |res| {
+        if let Ok(complete) = res {
+            task_runner.run(Box::new(move || complete.complete_catching(f)));
+        }
+    });
+
+    future
+}
+
+/// This method runs a closure on a task runner, moving `val` into it. This is
+/// the same as `background`, except that the value the closure consumes is
+/// passed in explicitly rather than captured.
+pub fn defer_with<R, T, F, V>(task_runner: R, val: T, f: F) -> Future<V, ()>
+        where R: Run<Box<TaskBox>> + Send + 'static,
+              T: Send + 'static,
+              F: FnOnce(T) -> V + Send + 'static,
+              V: Send + 'static {
+    defer(task_runner, Future::<T, ()>::of(val)).and_then(move |val| {
+        Ok(f(val))
+    })
+}
+
+/// A fixed size pool of threads for running closures in the background.
+///
+/// The pool can also be used as the task runner for `defer` and
+/// `background`.
+pub struct ThreadPool {
+    pool: syncbox::ThreadPool<Box<TaskBox>, LinkedQueue<Option<Box<TaskBox>>>>,
+}
+
+impl ThreadPool {
+    /// Creates a new pool backed by `threads` threads.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `threads` is 0.
+    pub fn new(threads: usize) -> ThreadPool {
+        assert!(threads > 0, "thread pool size must be greater than 0");
+
+        ThreadPool {
+            pool: syncbox::ThreadPool::fixed_size(threads as u32),
+        }
+    }
+
+    /// Returns a `Future` representing the completion of the given closure,
+    /// which is executed on one of the pool's threads.
+    ///
+    /// If the returned future is dropped before the closure starts running,
+    /// the closure is never invoked.
+    ///
+    /// ```
+    /// use eventual::*;
+    ///
+    /// let pool = ThreadPool::new(2);
+    /// let future = pool.spawn(|| (0..100).fold(0, |v, _| v + 1));
+    ///
+    /// assert_eq!(100, future.await().unwrap());
+    /// ```
+    pub fn spawn<F, V>(&self, f: F) -> Future<V, ()>
+            where F: FnOnce() -> V + Send + 'static,
+                  V: Send + 'static {
+
+        let (complete, future) = Future::pair();
+
+        self.pool.run(Box::new(move || {
+            if !complete.is_err() {
+                complete.complete(f());
+            }
+        }));
+
+        future
+    }
+}
+
+impl Run<Box<TaskBox>> for ThreadPool {
+    fn run(&self, task: Box<TaskBox>) {
+        self.pool.run(task);
+    }
+}
+
+impl Clone for ThreadPool {
+    fn clone(&self) -> ThreadPool {
+        ThreadPool { pool: self.pool.clone() }
+    }
+}
@@ -1,6 +1,6 @@
 #![allow(unused_assignments)]
 
-use super::{Async, Future, Complete, Cancel, AsyncError};
+use super::{Async, AsyncResult, Future, Complete, Cancel, AsyncError, Stream, Sender};
 use syncbox::atomic::{self, AtomicU64, Ordering};
 use std::cell::UnsafeCell;
 use std::sync::Arc;
@@ -20,6 +20,173 @@ pub fn select<S: Select<E>, E: Send + 'static>(asyncs: S) -> Future<(u32, S), E>
     res
 }
 
+/// Returns a future that completes with the value of the first future in
+/// `futures` to complete, along with the futures that are still pending.
+///
+/// The remaining futures keep their original relative order and are still
+/// live; dropping them cancels their computations. If the first future to
+/// complete fails, the returned future fails with the same error and the
+/// remaining futures are canceled. If `futures` is empty, the returned future
+/// is aborted.
+pub fn select_any<V, E>(futures: Vec<Future<V, E>>) -> Future<(V, Vec<Future<V, E>>), E>
+        where V: Send + 'static, E: Send + 'static {
+
+    select(futures).and_then(|(i, mut futures)| {
+        let val = futures.remove(i as usize).expect().ok()
+            .expect("selected value not successful");
+
+        Ok((val, futures))
+    })
+}
+
+/// Returns a future that completes with the value of the first future in
+/// `futures` to succeed, along with its index in `futures`.
+///
+/// Failed futures are skipped and the remaining futures are discarded once
+/// one of them succeeds. If every future fails, the returned future fails
+/// with all of the errors, in the original order of `futures`. If none
+/// succeeds and any of them was aborted, the returned future is aborted.
+pub fn select_ok<V, E>(futures: Vec<Future<V, E>>) -> Future<(usize, V), Vec<E>>
+        where V: Send + 'static, E: Send + 'static {
+
+    // Wrap the futures so that their failures do not fail the select
+    let futures = futures.into_iter()
+        .enumerate()
+        .map(|(i, f)| f.then(move |res| Ok::<_, ()>((i, res))))
+        .collect();
+
+    let (complete, ret) = Future::pair();
+
+    complete.receive(move |res| {
+        if let Ok(complete) = res {
+            do_select_ok(futures, Vec::new(), false, complete);
+        }
+    });
+
+    ret
+}
+
+fn do_select_ok<V, E>(futures: Vec<Future<(usize, AsyncResult<V, E>), ()>>,
+                      mut errors: Vec<(usize, E)>,
+                      aborted: bool,
+                      complete: Complete<(usize, V), Vec<E>>)
+        where V: Send + 'static, E: Send + 'static {
+
+    if futures.is_empty() {
+        if aborted {
+            complete.abort();
+        } else {
+            errors.sort_by_key(|&(i, _)| i);
+            complete.fail(errors.into_iter().map(|(_, e)| e).collect());
+        }
+
+        return;
+    }
+
+    select_any(futures).receive(move |res| {
+        match res {
+            Ok(((i, Ok(v)), _)) => complete.complete((i, v)),
+            Ok(((i, Err(AsyncError::Failed(e))), rest)) => {
+                errors.push((i, e));
+                do_select_ok(rest, errors, aborted, complete);
+            }
+            Ok(((_, Err(AsyncError::Aborted)), rest)) => {
+                do_select_ok(rest, errors, true, complete);
+            }
+            Err(_) => complete.abort(),
+        }
+    });
+}
+
+/// Returns a stream of the values of both `a` and `b`, yielded in the order
+/// that they become available.
+///
+/// Once one of the streams ends, the values of the other stream are yielded
+/// until it ends as well. If either stream fails, the returned stream fails
+/// with the same error and the other stream is canceled.
+pub fn select_stream<V, E>(a: Stream<V, E>, b: Stream<V, E>) -> Stream<V, E>
+        where V: Send + 'static, E: Send + 'static {
+
+    let (sender, stream) = Stream::pair();
+
+    sender.receive(move |res| {
+        if let Ok(sender) = res {
+            interleave(a, b, false, sender, identity, identity);
+        }
+    });
+
+    stream
+}
+
+// Sends the values of both streams, converted to the output type, in the
+// order that they become available. When both streams have a value, the one
+// that did not send last goes first so that a stream that is always ready
+// cannot starve the other.
+pub fn interleave<T, U, V, E>(a: Stream<T, E>,
+                              b: Stream<U, E>,
+                              prefer_b: bool,
+                              sender: Sender<V, E>,
+                              fa: fn(T) -> V,
+                              fb: fn(U) -> V)
+        where T: Send + 'static,
+              U: Send + 'static,
+              V: Send + 'static,
+              E: Send + 'static {
+
+    select((a, b)).receive(move |res| {
+        let (a, b) = match res {
+            Ok((_, streams)) => streams,
+            Err(AsyncError::Failed(e)) => return sender.fail(e),
+            Err(AsyncError::Aborted) => return sender.abort(),
+        };
+
+        let take_a = if prefer_b { !b.is_ready() } else { a.is_ready() };
+
+        if take_a {
+            match a.expect() {
+                Ok(Some((v, rest))) => {
+                    sender.send(fa(v)).receive(move |res| {
+                        if let Ok(sender) = res {
+                            interleave(rest, b, true, sender, fa, fb);
+                        }
+                    });
+                }
+                Ok(None) => send_rest(b, sender, fb),
+                Err(AsyncError::Failed(e)) => sender.fail(e),
+                Err(AsyncError::Aborted) => sender.abort(),
+            }
+        } else {
+            match b.expect() {
+                Ok(Some((v, rest))) => {
+                    sender.send(fb(v)).receive(move |res| {
+                        if let Ok(sender) = res {
+                            interleave(a, rest, false, sender, fa, fb);
+                        }
+                    });
+                }
+                Ok(None) => send_rest(a, sender, fa),
+                Err(AsyncError::Failed(e)) => sender.fail(e),
+                Err(AsyncError::Aborted) => sender.abort(),
+            }
+        }
+    });
+}
+
+fn identity<V>(v: V) -> V {
+    v
+}
+
+fn send_rest<T, V, E>(src: Stream<T, E>, sender: Sender<V, E>, f: fn(T) -> V)
+        where T: Send + 'static,
+              V: Send + 'static,
+              E: Send + 'static {
+
+    sender.send_all(src.map(f)).receive(|res| {
+        if let Err(AsyncError::Failed((e, sender))) = res {
+            sender.fail(e);
+        }
+    });
+}
 
 pub trait Select<E: Send + 'static> : Sized + Send + 'static {
     fn select(self, complete: Complete<(u32, Self), E>);
@@ -43,9 +210,6 @@ pub trait Select<E: Send + 'static> : Sized + Send + 'static {
 //
 // ## TODO
 //
-// - On selection failure, cancel all other pending futures. This will only
-//   matter if / when interest cancellation gets proxied up.
-//
 // - Switch to associated types over Index & Error
 //     Blocked: rust-lang/rust#21664
 
@@ -55,6 +219,8 @@ trait Values<S: Select<E>, E: Send + 'static> {
     fn consume(&mut self) -> S;
 
     fn cancel_callbacks(&mut self, selected: u32, up_to: u32, tokens: &mut Self::Tokens) -> u32;
+
+    fn discard(&mut self);
 }
 
 struct Selection<V: Values<S, E>, S: Select<E>, E: Send + 'static> {
@@ -101,6 +267,11 @@ impl<V: Values<S, E>, S: Select<E>, E: Send + 'static> Selection<V, S, E> {
             if async.is_err() {
                 debug!("first realized async val is error");
 
+                // The selection will never complete successfully, drop the
+                // async values whose callbacks were canceled, which cancels
+                // their computations.
+                self.core_mut().vals.discard();
+
                 let complete = self.core_mut().complete.take()
                     .expect("result future previously completed");
 
@@ -145,10 +316,14 @@ impl<V: Values<S, E>, S: Select<E>, E: Send + 'static> Selection<V, S, E> {
 
             // Attempt to cancel the callback
             if let Some(async) = cancel.cancel() {
-                // Store off the async
-                *aref = Some(async);
+                // If the selection failed, the async is dropped here, which
+                // cancels its computation.
+                if !curr.is_err() {
+                    // Store off the async
+                    *aref = Some(async);
 
-                self.dec_remaining(1, curr);
+                    self.dec_remaining(1, curr);
+                }
 
                 return false;
             }
@@ -192,6 +367,12 @@ impl<V: Values<S, E>, S: Select<E>, E: Send + 'static> Selection<V, S, E> {
         complete.complete((curr.selected(), core.vals.consume()));
     }
 
+    // Returns true if the selection has already failed, in which case any
+    // async values not yet registered are dropped, canceling them.
+    fn is_err(&self) -> bool {
+        self.core().state.load(Ordering::Relaxed).is_err()
+    }
+
     fn core(&self) -> &Core<V, S, E> {
         use std::mem;
         unsafe { mem::transmute(self.core.get()) }
@@ -238,7 +419,7 @@ macro_rules! components {
 
             $pending = $selection.track_callback(
                 c, expr!(&mut core.vals.$id), expr!(&mut core.tokens.$id));
-        } else {
+        } else if !$selection.is_err() {
             expr!($selection.core_mut().vals.$id) = Some($async);
             $handled += 1;
         }
@@ -502,6 +683,10 @@ impl<A1: Async<Error=E>, A2: Async<Error=E>, E> Values<(A1, A2), E> for (Option<
         (self.0.take().unwrap(), self.1.take().unwrap())
     }
 
+    fn discard(&mut self) {
+        *self = (None, None);
+    }
+
     fn cancel_callbacks(&mut self,
                         selected: u32,
                         up_to: u32,
@@ -538,6 +723,10 @@ impl<A1: Async<Error=E>, A2: Async<Error=E>, A3: Async<Error=E>, E> Values<(A1,
         (self.0.take().unwrap(), self.1.take().unwrap(), self.2.take().unwrap())
     }
 
+    fn discard(&mut self) {
+        *self = (None, None, None);
+    }
+
     fn cancel_callbacks(&mut self,
                         selected: u32,
                         up_to: u32,
@@ -552,3 +741,83 @@ impl<A1: Async<Error=E>, A2: Async<Error=E>, A3: Async<Error=E>, E> Values<(A1,
         ret
     }
 }
+
+/*
+ *
+ * ===== Select for Vec =====
+ *
+ */
+
+impl<A: Async<Error=E>, E: Send + 'static> Select<E> for Vec<A> {
+    fn select(self, complete: Complete<(u32, Vec<A>), E>) {
+        let len = self.len();
+        let vals: Vec<Option<A>> = (0..len).map(|_| None).collect();
+        let tokens: Vec<Option<A::Cancel>> = (0..len).map(|_| None).collect();
+
+        // Create the selection
+        let selection = Selection::new(vals, tokens, len as u32, complete);
+
+        let mut pending = true;
+        let mut handled = 0;
+
+        for (i, async) in self.into_iter().enumerate() {
+            if pending {
+                let s = selection.clone();
+                let c = async.ready(move |a| {
+                    s.async_ready(a, i as u32, &mut s.core_mut().vals[i])
+                });
+
+                let core = selection.core_mut();
+
+                pending = selection.track_callback(
+                    c, &mut core.vals[i], &mut core.tokens[i]);
+            } else if !selection.is_err() {
+                selection.core_mut().vals[i] = Some(async);
+                handled += 1;
+            }
+        }
+
+        if handled > 0 {
+            selection.dec_remaining(handled, selection.core().state.load(Ordering::Relaxed));
+        }
+    }
+}
+
+impl<A: Async<Error=E>, E: Send + 'static> Values<Vec<A>, E> for Vec<Option<A>> {
+    type Tokens = Vec<Option<A::Cancel>>;
+
+    fn consume(&mut self) -> Vec<A> {
+        self.iter_mut().map(|v| v.take().unwrap()).collect()
+    }
+
+    fn discard(&mut self) {
+        // Callbacks that could not be canceled still index into the vec, so
+        // keep its length.
+        for v in self.iter_mut() {
+            *v = None;
+        }
+    }
+
+    fn cancel_callbacks(&mut self,
+                        selected: u32,
+                        up_to: u32,
+                        tokens: &mut Vec<Option<A::Cancel>>) -> u32 {
+
+        let mut ret = 0;
+
+        for i in 0..(up_to as usize) {
+            if selected as usize == i {
+                continue;
+            }
+
+            let cancel = tokens[i].take().expect("cancel token missing");
+
+            if let Some(async) = cancel.cancel() {
+                self[i] = Some(async);
+                ret += 1;
+            }
+        }
+
+        ret
+    }
+}
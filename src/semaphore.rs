@@ -0,0 +1,192 @@
+use {Future, Complete};
+use std::collections::VecDeque;
+use std::error::Error;
+use std::sync::{Arc, Mutex};
+use std::fmt;
+
+/// Limits the number of concurrent accesses to a resource from async code.
+///
+/// `acquire` returns a future that completes with a `Permit` once one is
+/// available. The permit is handed back to the semaphore when it is dropped.
+/// Acquisitions that have to wait are served in the order they were made.
+/// Clones of a semaphore share the same permits.
+///
+/// ```
+/// use eventual::*;
+///
+/// let sem = Semaphore::new(1);
+///
+/// let permit = sem.acquire().await().unwrap();
+/// let next = sem.acquire();
+/// assert!(!next.is_ready());
+///
+/// drop(permit);
+/// assert!(next.await().is_ok());
+/// ```
+pub struct Semaphore {
+    inner: Arc<Mutex<Inner>>,
+}
+
+impl Semaphore {
+    /// Returns a new semaphore with the given number of permits.
+    pub fn new(permits: usize) -> Semaphore {
+        Semaphore {
+            inner: Arc::new(Mutex::new(Inner {
+                permits: permits,
+                waiters: VecDeque::new(),
+                closed: false,
+            })),
+        }
+    }
+
+    /// Returns a future that completes with a `Permit` once one is
+    /// available, or fails if the semaphore is closed first.
+    ///
+    /// Dropping the future before it completes gives up its place in the
+    /// queue.
+    pub fn acquire(&self) -> Future<Permit, SemaphoreError> {
+        let mut inner = self.inner.lock().unwrap();
+
+        if inner.closed {
+            return Future::error(SemaphoreError);
+        }
+
+        if inner.permits > 0 && inner.waiters.is_empty() {
+            inner.permits -= 1;
+            return Future::of(Permit { inner: self.inner.clone() });
+        }
+
+        let (complete, ret) = Future::pair();
+        inner.waiters.push_back(complete);
+        ret
+    }
+
+    /// Returns a `Permit` if one is available right away.
+    pub fn try_acquire(&self) -> Option<Permit> {
+        let mut inner = self.inner.lock().unwrap();
+
+        if inner.closed || inner.permits == 0 || !inner.waiters.is_empty() {
+            return None;
+        }
+
+        inner.permits -= 1;
+        Some(Permit { inner: self.inner.clone() })
+    }
+
+    /// Returns the number of permits that are currently available.
+    pub fn available_permits(&self) -> usize {
+        self.inner.lock().unwrap().permits
+    }
+
+    /// Closes the semaphore. Pending and later acquisitions fail with
+    /// `SemaphoreError`, while permits that have already been acquired
+    /// remain valid.
+    pub fn close(&self) {
+        let waiters: Vec<_> = {
+            let mut inner = self.inner.lock().unwrap();
+            inner.closed = true;
+            inner.waiters.drain(..).collect()
+        };
+
+        for complete in waiters {
+            complete.fail(SemaphoreError);
+        }
+    }
+}
+
+impl Clone for Semaphore {
+    fn clone(&self) -> Semaphore {
+        Semaphore { inner: self.inner.clone() }
+    }
+}
+
+impl fmt::Debug for Semaphore {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "Semaphore {{ available_permits: {} }}", self.available_permits())
+    }
+}
+
+/*
+ *
+ * ===== Permit =====
+ *
+ */
+
+/// A permit acquired from a `Semaphore`, handed back to it when dropped.
+pub struct Permit {
+    inner: Arc<Mutex<Inner>>,
+}
+
+impl Drop for Permit {
+    fn drop(&mut self) {
+        // The waiter is completed with the lock released, since that may
+        // invoke callbacks which could in turn drop the new permit.
+        let waiter = {
+            let mut inner = self.inner.lock().unwrap();
+            let waiter = inner.next_waiter();
+
+            if waiter.is_none() {
+                inner.permits += 1;
+            }
+
+            waiter
+        };
+
+        if let Some(complete) = waiter {
+            complete.complete(Permit { inner: self.inner.clone() });
+        }
+    }
+}
+
+impl fmt::Debug for Permit {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "Permit")
+    }
+}
+
+/*
+ *
+ * ===== Internals =====
+ *
+ */
+
+struct Inner {
+    permits: usize,
+    waiters: VecDeque<Complete<Permit, SemaphoreError>>,
+    closed: bool,
+}
+
+impl Inner {
+    // Returns the first waiter that is still interested in a permit
+    fn next_waiter(&mut self) -> Option<Complete<Permit, SemaphoreError>> {
+        while let Some(complete) = self.waiters.pop_front() {
+            if !complete.is_cancelled() {
+                return Some(complete);
+            }
+        }
+
+        None
+    }
+}
+
+/*
+ *
+ * ===== SemaphoreError =====
+ *
+ */
+
+/// The error an acquisition fails with when the `Semaphore` has been closed.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct SemaphoreError;
+
+impl Error for SemaphoreError {
+    fn description(&self) -> &str {
+        "semaphore closed"
+    }
+}
+
+impl fmt::Display for SemaphoreError {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "semaphore closed")
+    }
+}
@@ -1,8 +1,8 @@
-use {Async, AsyncError, Stream, Sender};
-use std::{mem, ops};
+use {Async, AsyncError, AsyncResult, Cancel, Complete, Future, Stream, Sender};
+use std::{cmp, mem, ops};
 use std::cell::UnsafeCell;
-use std::iter::IntoIterator;
-use std::sync::Arc;
+use std::iter::{Enumerate, IntoIterator};
+use std::sync::{Arc, Mutex};
 use std::sync::atomic::{self, AtomicUsize, Ordering};
 
 /// Returns a `Stream` consisting of the completion of the supplied async
@@ -24,6 +24,211 @@ pub fn sequence<I, A>(asyncs: I) -> Stream<A::Value, A::Error>
     rx
 }
 
+/// Returns a `Stream` consisting of the completion of the async values
+/// yielded by the supplied stream, in the order that they are yielded.
+///
+/// Each async value is waited on and its value sent before the next one is
+/// requested from the stream, so work items can themselves arrive
+/// asynchronously. If the stream or any of the async values fails, the
+/// returned stream fails with the same error.
+pub fn sequence_stream<A>(asyncs: Stream<A, A::Error>) -> Stream<A::Value, A::Error>
+        where A: Async {
+    asyncs.map_async(|async| async)
+}
+
+/// Returns a `Stream` consisting of the completion of the supplied async
+/// values in their original order.
+///
+/// Unlike `sequence_stream`, all of the async values are waited on at once
+/// as soon as the consumer is ready for the first value. Values that complete
+/// ahead of their turn are buffered until the ones before them have been
+/// sent. If any of the async values fails, the returned stream fails with
+/// the same error once the values before it have been sent.
+pub fn par_sequence<I, A>(asyncs: I) -> Stream<A::Value, A::Error>
+        where I: IntoIterator<Item=A>,
+              A: Async {
+
+    let asyncs: Vec<A> = asyncs.into_iter().collect();
+    let n = cmp::max(asyncs.len(), 1);
+
+    Stream::from_iter(asyncs).buffered(n)
+}
+
+/// Returns a `Future` that completes with the values of the supplied async
+/// values, in the order that they are completed, while waiting on at most `n`
+/// of them at a time.
+///
+/// The next async value is only waited on once one of the in-flight values
+/// completes. If any of them fails, the returned future fails with the same
+/// error, the async values that are still in flight are canceled and the ones
+/// that were not yet waited on are dropped.
+///
+/// # Panics
+///
+/// Panics if `n` is 0.
+pub fn sequence_n<I, A>(asyncs: I, n: usize) -> Future<Vec<A::Value>, A::Error>
+        where I: IntoIterator<Item=A>,
+              I::IntoIter: Send + 'static,
+              A: Async {
+
+    bounded(asyncs, n).map(|vals| {
+        vals.into_iter().map(|(_, v)| v).collect()
+    })
+}
+
+/// Same as `sequence_n`, but the values are in the order of the supplied
+/// async values rather than in completion order.
+pub fn sequence_n_ordered<I, A>(asyncs: I, n: usize) -> Future<Vec<A::Value>, A::Error>
+        where I: IntoIterator<Item=A>,
+              I::IntoIter: Send + 'static,
+              A: Async {
+
+    bounded(asyncs, n).map(|mut vals| {
+        vals.sort_by_key(|&(i, _)| i);
+        vals.into_iter().map(|(_, v)| v).collect()
+    })
+}
+
+// Completes with the values tagged with their position in `asyncs`, in
+// completion order.
+fn bounded<I, A>(asyncs: I, n: usize) -> Future<Vec<(usize, A::Value)>, A::Error>
+        where I: IntoIterator<Item=A>,
+              I::IntoIter: Send + 'static,
+              A: Async {
+
+    assert!(n > 0, "concurrency limit must be greater than 0");
+
+    let (complete, ret) = Future::pair();
+    let iter = asyncs.into_iter();
+
+    // Don't do any work until the consumer registers interest in the completed
+    // value.
+    complete.receive(move |res| {
+        if let Ok(complete) = res {
+            let state = Arc::new(Mutex::new(Bounded {
+                iter: Some(iter.enumerate()),
+                vals: Vec::new(),
+                in_flight: 0,
+                tokens: Vec::new(),
+                complete: Some(complete),
+            }));
+
+            for _ in 0..n {
+                if !start_next(&state) {
+                    break;
+                }
+            }
+        }
+    });
+
+    ret
+}
+
+struct Bounded<I: Iterator> where I::Item: Async {
+    // Async values not yet waited on, dropped on failure
+    iter: Option<Enumerate<I>>,
+    vals: Vec<(usize, <I::Item as Async>::Value)>,
+    in_flight: usize,
+    // Cancel tokens of the async values waited on, used to cancel the ones
+    // still in flight on failure
+    tokens: Vec<<I::Item as Async>::Cancel>,
+    complete: Option<Complete<Vec<(usize, <I::Item as Async>::Value)>, <I::Item as Async>::Error>>,
+}
+
+// Waits on the next async value, returns false if there are none left. The
+// lock is never held while registering callbacks as they may be invoked
+// immediately.
+fn start_next<I>(state: &Arc<Mutex<Bounded<I>>>) -> bool
+        where I: Iterator + Send + 'static,
+              I::Item: Async {
+
+    let mut curr = state.lock().unwrap();
+
+    let next = match curr.iter {
+        Some(ref mut iter) => iter.next(),
+        None => return false,
+    };
+
+    match next {
+        Some((i, async)) => {
+            curr.in_flight += 1;
+            drop(curr);
+
+            let cancel = {
+                let state = state.clone();
+                async.ready(move |async| async_complete(&state, i, async.expect()))
+            };
+
+            let mut curr = state.lock().unwrap();
+            curr.tokens.push(cancel);
+
+            // The sequence may have failed while the callback was being
+            // registered.
+            if curr.iter.is_none() {
+                let tokens = mem::replace(&mut curr.tokens, Vec::new());
+                drop(curr);
+
+                cancel_all::<I::Item>(tokens);
+                return false;
+            }
+
+            true
+        }
+        None => {
+            if curr.in_flight == 0 {
+                if let Some(complete) = curr.complete.take() {
+                    let vals = mem::replace(&mut curr.vals, Vec::new());
+                    drop(curr);
+
+                    complete.complete(vals);
+                }
+            }
+
+            false
+        }
+    }
+}
+
+fn async_complete<I>(state: &Arc<Mutex<Bounded<I>>>,
+                     i: usize,
+                     res: AsyncResult<<I::Item as Async>::Value, <I::Item as Async>::Error>)
+        where I: Iterator + Send + 'static,
+              I::Item: Async {
+
+    let mut curr = state.lock().unwrap();
+    curr.in_flight -= 1;
+
+    match res {
+        Ok(v) => {
+            curr.vals.push((i, v));
+            drop(curr);
+
+            start_next(state);
+        }
+        Err(e) => {
+            let complete = curr.complete.take();
+            let iter = curr.iter.take();
+            let tokens = mem::replace(&mut curr.tokens, Vec::new());
+            drop(curr);
+
+            // Drop the remaining async values and cancel the ones in flight
+            // outside of the lock
+            drop(iter);
+            cancel_all::<I::Item>(tokens);
+
+            if let (Some(complete), AsyncError::Failed(e)) = (complete, e) {
+                complete.fail(e);
+            }
+        }
+    }
+}
+
+fn cancel_all<A: Async>(tokens: Vec<A::Cancel>) {
+    for token in tokens {
+        drop(token.cancel());
+    }
+}
+
 // == !!! Warning !!! ==
 //
 // The code below uses an UnsafeCell to by-pass rust's memory model with
@@ -0,0 +1,177 @@
+use {Async, AsyncResult, BoxedReceive, Cancel, Future};
+use std::sync::{Arc, Mutex};
+use std::{fmt, mem};
+
+/// A handle to the result of a future that can be cloned, allowing any number
+/// of consumers to await or chain off of the same computation.
+///
+/// The original future is consumed once, when the first clone registers
+/// interest in the result, and each clone receives its own copy of the
+/// result. Dropping every clone before that point cancels the original
+/// future.
+pub struct SharedFuture<T: Clone + Send + 'static, E: Clone + Send + 'static> {
+    inner: Arc<Mutex<Inner<T, E>>>,
+}
+
+pub fn new<T, E>(future: Future<T, E>) -> SharedFuture<T, E>
+        where T: Clone + Send + 'static,
+              E: Clone + Send + 'static {
+
+    SharedFuture {
+        inner: Arc::new(Mutex::new(Inner {
+            state: State::Pending(future),
+            waiters: Vec::new(),
+            next_id: 0,
+        })),
+    }
+}
+
+struct Inner<T: Clone + Send + 'static, E: Clone + Send + 'static> {
+    state: State<T, E>,
+    waiters: Vec<Waiter<T, E>>,
+    next_id: u64,
+}
+
+enum State<T: Send + 'static, E: Send + 'static> {
+    // Nobody has registered interest yet
+    Pending(Future<T, E>),
+    // The original future has been consumed, waiting on its result
+    Running,
+    Complete(AsyncResult<T, E>),
+}
+
+struct Waiter<T: Clone + Send + 'static, E: Clone + Send + 'static> {
+    id: u64,
+    shared: SharedFuture<T, E>,
+    f: Box<BoxedReceive<SharedFuture<T, E>>>,
+}
+
+impl<T: Clone + Send + 'static, E: Clone + Send + 'static> Async for SharedFuture<T, E> {
+    type Value = T;
+    type Error = E;
+    type Cancel = SharedReceipt<T, E>;
+
+    fn is_ready(&self) -> bool {
+        match self.inner.lock().unwrap().state {
+            State::Complete(_) => true,
+            _ => false,
+        }
+    }
+
+    fn is_err(&self) -> bool {
+        match self.inner.lock().unwrap().state {
+            State::Complete(ref res) => res.is_err(),
+            _ => false,
+        }
+    }
+
+    fn poll(self) -> Result<AsyncResult<T, E>, SharedFuture<T, E>> {
+        let res = match self.inner.lock().unwrap().state {
+            State::Complete(ref res) => Some(res.clone()),
+            _ => None,
+        };
+
+        match res {
+            Some(res) => Ok(res),
+            None => Err(self),
+        }
+    }
+
+    fn ready<F: FnOnce(SharedFuture<T, E>) + Send + 'static>(self, f: F) -> SharedReceipt<T, E> {
+        let arc = self.inner.clone();
+        let mut inner = arc.lock().unwrap();
+
+        if let State::Complete(_) = inner.state {
+            drop(inner);
+            f(self);
+
+            return SharedReceipt { inner: None, id: 0 };
+        }
+
+        let id = inner.next_id;
+        inner.next_id += 1;
+
+        inner.waiters.push(Waiter {
+            id: id,
+            shared: self,
+            f: Box::new(f),
+        });
+
+        // The first registered interest starts the original future
+        let start = match mem::replace(&mut inner.state, State::Running) {
+            State::Pending(future) => Some(future),
+            state => {
+                inner.state = state;
+                None
+            }
+        };
+
+        drop(inner);
+
+        if let Some(future) = start {
+            let shared = arc.clone();
+            future.receive(move |res| complete(&shared, res));
+        }
+
+        SharedReceipt { inner: Some(arc), id: id }
+    }
+}
+
+// Stores the result and notifies all registered clones
+fn complete<T, E>(inner: &Mutex<Inner<T, E>>, res: AsyncResult<T, E>)
+        where T: Clone + Send + 'static,
+              E: Clone + Send + 'static {
+
+    let waiters = {
+        let mut inner = inner.lock().unwrap();
+        inner.state = State::Complete(res);
+        mem::replace(&mut inner.waiters, Vec::new())
+    };
+
+    // Invoke the callbacks outside of the lock, they may use the clone they
+    // receive.
+    for waiter in waiters {
+        waiter.f.receive_boxed(waiter.shared);
+    }
+}
+
+impl<T: Clone + Send + 'static, E: Clone + Send + 'static> Clone for SharedFuture<T, E> {
+    fn clone(&self) -> SharedFuture<T, E> {
+        SharedFuture { inner: self.inner.clone() }
+    }
+}
+
+impl<T: Clone + Send + 'static, E: Clone + Send + 'static> fmt::Debug for SharedFuture<T, E> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "SharedFuture {{ ... }}")
+    }
+}
+
+/*
+ *
+ * ===== SharedReceipt =====
+ *
+ */
+
+/// Cancels a callback registered on a `SharedFuture`.
+pub struct SharedReceipt<T: Clone + Send + 'static, E: Clone + Send + 'static> {
+    inner: Option<Arc<Mutex<Inner<T, E>>>>,
+    id: u64,
+}
+
+impl<T: Clone + Send + 'static, E: Clone + Send + 'static> Cancel<SharedFuture<T, E>> for SharedReceipt<T, E> {
+    fn cancel(self) -> Option<SharedFuture<T, E>> {
+        let id = self.id;
+        let inner = match self.inner {
+            Some(inner) => inner,
+            None => return None,
+        };
+
+        let mut inner = inner.lock().unwrap();
+
+        match inner.waiters.iter().position(|w| w.id == id) {
+            Some(i) => Some(inner.waiters.remove(i).shared),
+            None => None,
+        }
+    }
+}
@@ -0,0 +1,197 @@
+use {Async, Future, Complete, Sender};
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use std::{fmt, mem};
+
+/// A cloneable handle for sending values to a single `Stream` from any number
+/// of producers. See `Sender::shared`.
+///
+/// Values are delivered in the order they were sent across all clones. The
+/// stream ends once every clone has been dropped and the values sent so far
+/// have been delivered.
+pub struct SharedSender<T: Send + 'static, E: Send + 'static> {
+    inner: Arc<Mutex<Inner<T, E>>>,
+}
+
+pub fn new<T: Send + 'static, E: Send + 'static>(sender: Sender<T, E>) -> SharedSender<T, E> {
+    SharedSender {
+        inner: Arc::new(Mutex::new(Inner {
+            queue: VecDeque::new(),
+            sender: Some(sender),
+            refs: 1,
+            state: State::Open,
+        })),
+    }
+}
+
+impl<T: Send + 'static, E: Send + 'static> SharedSender<T, E> {
+    /// Sends a value to the stream. Returns a future that completes once the
+    /// consumer has accepted the value, or fails if the stream has been
+    /// dropped or failed.
+    pub fn send(&self, val: T) -> Future<(), ()> {
+        let (complete, ret) = Future::pair();
+
+        {
+            let mut inner = self.inner.lock().unwrap();
+
+            match inner.state {
+                State::Open => inner.queue.push_back((val, complete)),
+                _ => {
+                    drop(inner);
+                    complete.fail(());
+                    return ret;
+                }
+            }
+        }
+
+        poll(&self.inner);
+        ret
+    }
+
+    /// Fails the stream with the given error once the values sent so far
+    /// have been delivered. Values sent by other clones afterwards are
+    /// rejected.
+    pub fn fail(self, err: E) {
+        {
+            let mut inner = self.inner.lock().unwrap();
+
+            if let State::Open = inner.state {
+                inner.state = State::Failed(err);
+            }
+        }
+
+        // Dropping self delivers the error once the queue is drained
+    }
+}
+
+impl<T: Send + 'static, E: Send + 'static> Clone for SharedSender<T, E> {
+    fn clone(&self) -> SharedSender<T, E> {
+        self.inner.lock().unwrap().refs += 1;
+        SharedSender { inner: self.inner.clone() }
+    }
+}
+
+impl<T: Send + 'static, E: Send + 'static> Drop for SharedSender<T, E> {
+    fn drop(&mut self) {
+        {
+            let mut inner = self.inner.lock().unwrap();
+            inner.refs -= 1;
+
+            if inner.refs == 0 {
+                if let State::Open = inner.state {
+                    inner.state = State::Closed;
+                }
+            }
+        }
+
+        poll(&self.inner);
+    }
+}
+
+impl<T: Send + 'static, E: Send + 'static> fmt::Debug for SharedSender<T, E> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "SharedSender {{ ... }}")
+    }
+}
+
+/*
+ *
+ * ===== Internals =====
+ *
+ */
+
+struct Inner<T: Send + 'static, E: Send + 'static> {
+    queue: VecDeque<(T, Complete<(), ()>)>,
+    // The sender is only present when it is not in the middle of a send
+    sender: Option<Sender<T, E>>,
+    refs: usize,
+    state: State<E>,
+}
+
+enum State<E> {
+    Open,
+    // Every clone has been dropped
+    Closed,
+    Failed(E),
+    // The stream has been dropped, or has ended
+    Done,
+}
+
+enum Action<T: Send + 'static, E: Send + 'static> {
+    Send(Sender<T, E>, T, Complete<(), ()>),
+    End(Sender<T, E>, Option<E>),
+}
+
+impl<T: Send + 'static, E: Send + 'static> Inner<T, E> {
+    // Returns the work to do now that the state has changed. Must be invoked
+    // with the lock held and the action performed once it is released.
+    fn action(&mut self) -> Option<Action<T, E>> {
+        let sender = match self.sender.take() {
+            Some(sender) => sender,
+            None => return None,
+        };
+
+        if let Some((val, complete)) = self.queue.pop_front() {
+            return Some(Action::Send(sender, val, complete));
+        }
+
+        match self.state {
+            State::Open => {
+                self.sender = Some(sender);
+                None
+            }
+            State::Closed => {
+                self.state = State::Done;
+                Some(Action::End(sender, None))
+            }
+            State::Failed(_) => {
+                match mem::replace(&mut self.state, State::Done) {
+                    State::Failed(e) => Some(Action::End(sender, Some(e))),
+                    _ => unreachable!(),
+                }
+            }
+            State::Done => None,
+        }
+    }
+}
+
+// Makes progress if the sender is not in the middle of a send. The lock is
+// never held while interacting with the stream, since that may invoke
+// callbacks.
+fn poll<T: Send + 'static, E: Send + 'static>(inner: &Arc<Mutex<Inner<T, E>>>) {
+    let action = inner.lock().unwrap().action();
+
+    match action {
+        Some(Action::Send(sender, val, complete)) => {
+            let inner = inner.clone();
+
+            sender.send(val).receive(move |res| {
+                match res {
+                    Ok(sender) => {
+                        inner.lock().unwrap().sender = Some(sender);
+                        complete.complete(());
+                        poll(&inner);
+                    }
+                    Err(_) => {
+                        // The stream has been dropped, reject everything
+                        // that is still queued
+                        let queued: Vec<_> = {
+                            let mut locked = inner.lock().unwrap();
+                            locked.state = State::Done;
+                            locked.queue.drain(..).map(|(_, complete)| complete).collect()
+                        };
+
+                        complete.fail(());
+
+                        for complete in queued {
+                            complete.fail(());
+                        }
+                    }
+                }
+            });
+        }
+        Some(Action::End(sender, None)) => drop(sender),
+        Some(Action::End(sender, Some(e))) => sender.fail(e),
+        None => {}
+    }
+}
@@ -0,0 +1,195 @@
+use {Async, AsyncError, Stream, Sender};
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+pub fn split<T, E, F>(source: Stream<T, E>, f: F) -> (Stream<T, E>, Stream<T, E>)
+        where T: Send + 'static,
+              E: Clone + Send + 'static,
+              F: Fn(&T) -> bool + Send + 'static {
+
+    let (tx_a, rx_a) = Stream::pair();
+    let (tx_b, rx_b) = Stream::pair();
+
+    let inner = Arc::new(Mutex::new(Inner {
+        source: Some((source, f)),
+        end: None,
+        outputs: [Output::new(), Output::new()],
+    }));
+
+    wait(&inner, 0, tx_a);
+    wait(&inner, 1, tx_b);
+
+    (rx_a, rx_b)
+}
+
+struct Inner<T: Send + 'static, E: Send + 'static, F> {
+    // The source is taken, along with the predicate, while waiting on its
+    // next value
+    source: Option<(Stream<T, E>, F)>,
+    // Set once the source has ended, `None` is a successful end
+    end: Option<Option<AsyncError<E>>>,
+    outputs: [Output<T, E>; 2],
+}
+
+struct Output<T: Send + 'static, E: Send + 'static> {
+    queue: VecDeque<T>,
+    // Present when the consumer is waiting on a value that is not available
+    // yet
+    sender: Option<Sender<T, E>>,
+    dropped: bool,
+}
+
+impl<T: Send + 'static, E: Send + 'static> Output<T, E> {
+    fn new() -> Output<T, E> {
+        Output {
+            queue: VecDeque::new(),
+            sender: None,
+            dropped: false,
+        }
+    }
+}
+
+enum Action<T: Send + 'static, E: Send + 'static, F> {
+    Send(usize, Sender<T, E>, T),
+    End(Sender<T, E>, Option<AsyncError<E>>),
+    Pull(Stream<T, E>, F),
+}
+
+impl<T, E, F> Inner<T, E, F>
+        where T: Send + 'static,
+              E: Clone + Send + 'static,
+              F: Fn(&T) -> bool + Send + 'static {
+
+    fn route(&mut self, i: usize, val: T) {
+        // Values for an output that has been dropped are discarded
+        if !self.outputs[i].dropped {
+            self.outputs[i].queue.push_back(val);
+        }
+    }
+
+    // Returns the work to do now that the state has changed. Must be invoked
+    // with the lock held and the actions performed once it is released.
+    fn actions(&mut self) -> Vec<Action<T, E, F>> {
+        let mut actions = vec![];
+        let mut pull = false;
+
+        for i in 0..2 {
+            if self.outputs[i].sender.is_none() {
+                continue;
+            }
+
+            if let Some(val) = self.outputs[i].queue.pop_front() {
+                let sender = self.outputs[i].sender.take().unwrap();
+                actions.push(Action::Send(i, sender, val));
+            } else if let Some(ref end) = self.end {
+                let sender = self.outputs[i].sender.take().unwrap();
+                actions.push(Action::End(sender, end.clone()));
+            } else {
+                pull = true;
+            }
+        }
+
+        if pull {
+            if let Some((source, f)) = self.source.take() {
+                actions.push(Action::Pull(source, f));
+            }
+        }
+
+        if self.outputs.iter().all(|output| output.dropped) {
+            // Nobody is interested in the values anymore, cancel the source
+            actions.clear();
+            self.source = None;
+        }
+
+        actions
+    }
+}
+
+fn run<T, E, F>(inner: &Arc<Mutex<Inner<T, E, F>>>, actions: Vec<Action<T, E, F>>)
+        where T: Send + 'static,
+              E: Clone + Send + 'static,
+              F: Fn(&T) -> bool + Send + 'static {
+
+    for action in actions {
+        match action {
+            Action::Send(i, sender, val) => {
+                let inner = inner.clone();
+                sender.send(val).receive(move |res| ready(&inner, i, res.ok()));
+            }
+            Action::End(sender, None) => drop(sender),
+            Action::End(sender, Some(AsyncError::Failed(e))) => sender.fail(e),
+            Action::End(sender, Some(AsyncError::Aborted)) => sender.abort(),
+            Action::Pull(source, f) => pull(inner, source, f),
+        }
+    }
+}
+
+// Waits for the consumer of the output to be ready for the next value
+fn wait<T, E, F, A>(inner: &Arc<Mutex<Inner<T, E, F>>>, i: usize, sender: A)
+        where T: Send + 'static,
+              E: Clone + Send + 'static,
+              F: Fn(&T) -> bool + Send + 'static,
+              A: Async<Value=Sender<T, E>> {
+
+    let inner = inner.clone();
+    sender.receive(move |res| ready(&inner, i, res.ok()));
+}
+
+fn ready<T, E, F>(inner: &Arc<Mutex<Inner<T, E, F>>>, i: usize, sender: Option<Sender<T, E>>)
+        where T: Send + 'static,
+              E: Clone + Send + 'static,
+              F: Fn(&T) -> bool + Send + 'static {
+
+    let actions = {
+        let mut locked = inner.lock().unwrap();
+
+        match sender {
+            Some(sender) => locked.outputs[i].sender = Some(sender),
+            None => {
+                // The output stream has been dropped
+                locked.outputs[i].dropped = true;
+                locked.outputs[i].queue.clear();
+            }
+        }
+
+        locked.actions()
+    };
+
+    run(inner, actions);
+}
+
+fn pull<T, E, F>(inner: &Arc<Mutex<Inner<T, E, F>>>, source: Stream<T, E>, f: F)
+        where T: Send + 'static,
+              E: Clone + Send + 'static,
+              F: Fn(&T) -> bool + Send + 'static {
+
+    let inner = inner.clone();
+
+    source.receive(move |res| {
+        // The predicate is invoked without holding the lock, so that a panic
+        // in it does not poison the lock for the other output
+        let res = res.map(|head| {
+            head.map(|(val, rest)| {
+                let i = if f(&val) { 0 } else { 1 };
+                (i, val, rest)
+            })
+        });
+
+        let actions = {
+            let mut locked = inner.lock().unwrap();
+
+            match res {
+                Ok(Some((i, val, rest))) => {
+                    locked.route(i, val);
+                    locked.source = Some((rest, f));
+                }
+                Ok(None) => locked.end = Some(None),
+                Err(e) => locked.end = Some(Some(e)),
+            }
+
+            locked.actions()
+        };
+
+        run(&inner, actions);
+    });
+}
@@ -7,10 +7,16 @@ use {
     Cancel,
     Receipt,
     AsyncResult,
-    AsyncError
+    AsyncError,
+    Either
 };
+use shared_sender::{self, SharedSender};
 use super::core::{self, Core};
+use timer;
+use time::{Duration, SteadyTime};
+use std::collections::VecDeque;
 use std::fmt;
+use std::iter;
 
 /*
  *
@@ -29,6 +35,21 @@ pub type Head<T, E> = Option<(T, Stream<T, E>)>;
 // Shorthand for the core type for Streams
 pub type StreamCore<T, E> = Core<Head<T, E>, E>;
 
+/// Returns a new `Sender` along with the `Stream` it sends values to. This is
+/// the same as `Stream::pair`.
+///
+/// ```
+/// use eventual::*;
+///
+/// let (tx, rx) = channel::<i32, ()>();
+/// tx.send(1).and_then(|tx| tx.send(2)).fire();
+///
+/// assert_eq!(Ok(vec![1, 2]), rx.collect().await());
+/// ```
+pub fn channel<T: Send + 'static, E: Send + 'static>() -> (Sender<T, E>, Stream<T, E>) {
+    Stream::pair()
+}
+
 impl<T: Send + 'static, E: Send + 'static> Stream<T, E> {
 
     /// Creates a new `Stream`, returning it with the associated `Sender`.
@@ -51,16 +72,149 @@ impl<T: Send + 'static, E: Send + 'static> Stream<T, E> {
         Stream { core: Some(Core::with_value(Ok(None))) }
     }
 
+    /// Returns a Stream that will immediately yield the supplied value and
+    /// then end, or fail with the supplied error.
+    ///
+    /// ```
+    /// use eventual::*;
+    ///
+    /// let stream = Stream::<i32, &'static str>::from_result(Ok(1));
+    /// assert_eq!(vec![1], stream.iter().collect::<Vec<i32>>());
+    /// ```
+    pub fn from_result(res: Result<T, E>) -> Stream<T, E> {
+        let head = match res {
+            Ok(val) => Ok(Some((val, Stream::empty()))),
+            Err(err) => Err(AsyncError::failed(err)),
+        };
+
+        Stream { core: Some(Core::with_value(head)) }
+    }
+
+    /// Returns a Stream that yields the values of the supplied iterator and
+    /// then ends.
+    ///
+    /// The iterator is advanced lazily, only once the consumer is ready for
+    /// the next value, so an infinite iterator is fine as long as the
+    /// consumer stops at some point.
+    ///
+    /// ```
+    /// use eventual::*;
+    ///
+    /// let stream = Stream::<u32, ()>::from_iter(0..);
+    /// assert_eq!(Ok(vec![0, 1, 2]), stream.take(3).collect().await());
+    /// ```
+    pub fn from_iter<I>(iter: I) -> Stream<T, E>
+            where I: IntoIterator<Item=T>,
+                  I::IntoIter: Send + 'static {
+
+        let (sender, stream) = Stream::pair();
+        sender.send_all(iter).fire();
+        stream
+    }
+
+    /// Returns a Stream that yields clones of the supplied value forever.
+    ///
+    /// ```
+    /// use eventual::*;
+    ///
+    /// let stream = Stream::<&'static str, ()>::repeat("tick");
+    /// assert_eq!(Ok(vec!["tick", "tick"]), stream.take(2).collect().await());
+    /// ```
+    pub fn repeat(val: T) -> Stream<T, E> where T: Clone {
+        Stream::from_iter(iter::repeat(val))
+    }
+
+    /// Returns a Stream that yields `n` clones of the supplied value and then
+    /// ends.
+    ///
+    /// ```
+    /// use eventual::*;
+    ///
+    /// let stream = Stream::<u32, ()>::repeat_n(7, 3);
+    /// assert_eq!(Ok(vec![7, 7, 7]), stream.collect().await());
+    /// ```
+    pub fn repeat_n(val: T, n: usize) -> Stream<T, E> where T: Clone {
+        Stream::from_iter(iter::repeat(val).take(n))
+    }
+
+    /// Returns a Stream that yields the value of the supplied async value and
+    /// then ends, or fails with its error.
+    ///
+    /// ```
+    /// use eventual::*;
+    ///
+    /// let stream = Stream::once(Future::<i32, ()>::of(1));
+    /// assert_eq!(Ok(vec![1]), stream.collect().await());
+    /// ```
+    pub fn once<A: Async<Value=T, Error=E>>(async: A) -> Stream<T, E> {
+        async.map(|val| Some((val, Stream::empty()))).to_stream()
+    }
+
+    /// Returns a Stream that yields the values produced by repeatedly
+    /// invoking `f` with the current state, starting from `seed`. The stream
+    /// ends once `f` returns `None`.
+    ///
+    /// `f` is only invoked once the consumer is ready for the next value.
+    ///
+    /// ```
+    /// use eventual::*;
+    ///
+    /// let fib = Stream::<u64, ()>::unfold((0, 1), |(a, b)| Some((a, (b, a + b))));
+    /// assert_eq!(Ok(vec![0, 1, 1, 2, 3, 5]), fib.take(6).collect().await());
+    /// ```
+    pub fn unfold<S, F>(seed: S, f: F) -> Stream<T, E>
+            where S: Send + 'static,
+                  F: FnMut(S) -> Option<(T, S)> + Send + 'static {
+
+        let (sender, stream) = Stream::pair();
+        unfold_step(seed, f, sender);
+        stream
+    }
+
     /// Asyncronously collects the items from the `Stream`, returning them sorted by order of
     /// arrival.
+    ///
+    /// If the stream fails, the returned future fails with the same error and
+    /// the items collected so far are dropped.
     pub fn collect(self) -> Future<Vec<T>, E> {
         let buffer = Vec::new();
-        self.reduce(buffer, |mut vec, item| { vec.push(item); return vec })
+        self.fold(buffer, |mut vec, item| { vec.push(item); return vec })
+    }
+
+    /// Returns a future that completes with the first value of the stream,
+    /// or `None` if the stream is empty. The rest of the stream is canceled.
+    ///
+    /// If the stream fails before yielding a value, the returned future
+    /// fails with the same error.
+    pub fn first(self) -> Future<Option<T>, E> {
+        // Dropping the rest of the stream cancels it
+        Async::map(self, |head| head.map(|(item, _)| item))
+    }
+
+    /// Returns a future that completes with the last value of the stream,
+    /// or `None` if the stream is empty, once the stream has ended.
+    ///
+    /// If the stream fails, the returned future fails with the same error.
+    pub fn last(self) -> Future<Option<T>, E> {
+        self.fold(None, |_, item| Some(item))
     }
 
     /// Synchronously iterate over the `Stream`
+    ///
+    /// The iterator is double ended. Iterating from the back blocks until the
+    /// stream has ended, buffering all of its remaining values, so it must
+    /// only be done with finite streams.
     pub fn iter(mut self) -> StreamIter<T, E> {
-        StreamIter { core: Some(core::take(&mut self.core)) }
+        StreamIter {
+            core: Some(core::take(&mut self.core)),
+            buffered: VecDeque::new(),
+        }
+    }
+
+    /// Returns a `PeekableStream` that can buffer the next value of the
+    /// stream, allowing it to be inspected before it is consumed.
+    pub fn peekable(self) -> PeekableStream<T, E> {
+        PeekableStream { state: Peeked::No(self) }
     }
 
     /*
@@ -102,8 +256,24 @@ impl<T: Send + 'static, E: Send + 'static> Stream<T, E> {
         });
     }
 
+    /// Sequentially yields each value to the supplied function, waiting for
+    /// the async value it returns to complete before requesting the next
+    /// value. Returns a future representing the completion of the final
+    /// async value.
+    ///
+    /// If the stream or any of the returned async values fail, the returned
+    /// future fails with the same error and the rest of the stream is
+    /// canceled.
+    pub fn for_each<F, U>(self, mut f: F) -> Future<(), E>
+            where F: FnMut(T) -> U + Send + 'static,
+                  U: Async<Error=E> {
+
+        self.reduce_async((), move |_, v| f(v).map(|_| ()))
+    }
+
     /// Returns a new stream containing the values of the original stream that
-    /// match the given predicate.
+    /// match the given predicate. Values that do not match are dropped and the
+    /// next value of the original stream is requested right away.
     pub fn filter<F: FnMut(&T) -> bool + Send + 'static>(self, f: F) -> Stream<T, E> {
         let (sender, stream) = Stream::pair();
         self.do_filter(f, sender);
@@ -145,6 +315,9 @@ impl<T: Send + 'static, E: Send + 'static> Stream<T, E> {
     /// function to each value of the original stream. Each iteration waits for
     /// the async result of the mapping to realize before continuing on to the
     /// next value.
+    ///
+    /// If the async value fails, the returned stream fails with the same
+    /// error.
     pub fn map_async<F, U>(self, action: F) -> Stream<U::Value, E>
             where F: FnMut(T) -> U + Send + 'static,
                   U: Async<Error=E> {
@@ -188,6 +361,63 @@ impl<T: Send + 'static, E: Send + 'static> Stream<T, E> {
         });
     }
 
+    /// Returns a new stream representing the application of the specified
+    /// function to each value of the original stream along with a mutable
+    /// reference to state that is carried from one value to the next,
+    /// starting with `init`.
+    ///
+    /// The returned stream yields the values returned by the function and
+    /// ends as soon as the function returns `None`, canceling the rest of the
+    /// original stream.
+    pub fn scan<S, F, U>(self, init: S, f: F) -> Stream<U, E>
+            where S: Send + 'static,
+                  F: FnMut(&mut S, T) -> Option<U> + Send + 'static,
+                  U: Send + 'static {
+
+        let (sender, ret) = Stream::pair();
+
+        sender.receive(move |res| {
+            if let Ok(sender) = res {
+                self.do_scan(sender, init, f);
+            }
+        });
+
+        ret
+    }
+
+    fn do_scan<S, F, U>(self, sender: Sender<U, E>, mut state: S, mut f: F)
+            where S: Send + 'static,
+                  F: FnMut(&mut S, T) -> Option<U> + Send + 'static,
+                  U: Send + 'static {
+
+        self.receive(move |head| {
+            match head {
+                Ok(Some((v, rest))) => {
+                    if let Some(val) = f(&mut state, v) {
+                        sender.send(val).receive(move |res| {
+                            if let Ok(sender) = res {
+                                rest.do_scan(sender, state, f);
+                            }
+                        });
+                    }
+                }
+                Ok(None) => {}
+                Err(AsyncError::Failed(e)) => sender.fail(e),
+                Err(AsyncError::Aborted) => sender.abort(),
+            }
+        });
+    }
+
+    /// Returns a new stream yielding each value of the original stream along
+    /// with its position in the stream, starting at zero.
+    pub fn enumerate(self) -> Stream<(usize, T), E> {
+        self.scan(0, |i, v| {
+            let ret = (*i, v);
+            *i += 1;
+            Some(ret)
+        })
+    }
+
     /// Returns a new stream with an identical sequence of values as the
     /// original. If the original stream errors, apply the given function on
     /// the error and use the result as the error of the new stream.
@@ -224,6 +454,57 @@ impl<T: Send + 'static, E: Send + 'static> Stream<T, E> {
         });
     }
 
+    /// Returns a new stream with an identical sequence of values as the
+    /// original. If the original stream errors, the error is converted into
+    /// the error type of the new stream with `From`.
+    pub fn error_into<U>(self) -> Stream<T, U>
+            where U: From<E> + Send + 'static {
+        self.map_err(From::from)
+    }
+
+    /// Returns a new stream with an identical sequence of values as the
+    /// original. If the original stream errors, the given function is
+    /// invoked with the error; if it returns a value, that value is yielded
+    /// in place of the error, and either way the returned stream then ends
+    /// successfully.
+    ///
+    /// A stream cannot yield values once it has failed, so the replacement
+    /// is always the last value of the returned stream.
+    pub fn recover_item<F>(self, f: F) -> Stream<T, E>
+            where F: FnOnce(E) -> Option<T> + Send + 'static {
+        let (sender, stream) = Stream::pair();
+
+        sender.receive(move |res| {
+            if let Ok(sender) = res {
+                self.do_recover_item(sender, f);
+            }
+        });
+
+        stream
+    }
+
+    fn do_recover_item<F>(self, sender: Sender<T, E>, f: F)
+            where F: FnOnce(E) -> Option<T> + Send + 'static {
+        self.receive(move |res| {
+            match res {
+                Ok(Some((val, rest))) => {
+                    sender.send(val).receive(move |res| {
+                        if let Ok(sender) = res {
+                            rest.do_recover_item(sender, f);
+                        }
+                    });
+                }
+                Ok(None) => {}
+                Err(AsyncError::Failed(e)) => {
+                    if let Some(val) = f(e) {
+                        sender.send(val);
+                    }
+                }
+                Err(AsyncError::Aborted) => sender.abort(),
+            }
+        });
+    }
+
     pub fn process<F, U>(self, in_flight: usize, f: F) -> Stream<U::Value, E>
             where F: FnMut(T) -> U + Send + 'static,
                   U: Async<Error=E> {
@@ -231,16 +512,73 @@ impl<T: Send + 'static, E: Send + 'static> Stream<T, E> {
         process(self, in_flight, f)
     }
 
+    /// Returns a stream of the values of the async values yielded by the
+    /// original stream, keeping up to `n` of them in flight at once.
+    ///
+    /// Values are yielded in the order that the async values complete, not
+    /// in the order of the original stream. If any of them fails, the
+    /// returned stream fails with the same error.
+    pub fn buffer_unordered(self, n: usize) -> Stream<T::Value, E>
+            where T: Async<Error=E> {
+        self.process(n, |async| async)
+    }
+
+    /// Returns a stream of the values of the async values yielded by the
+    /// original stream, keeping up to `n` of them in flight at once.
+    ///
+    /// Unlike `buffer_unordered`, values are yielded in the order of the
+    /// original stream: an async value that completes early is held until
+    /// the ones before it have been yielded. If any of them fails, the
+    /// returned stream fails with the same error once it is reached.
+    ///
+    /// The async values still in flight are canceled once the returned
+    /// stream fails or is dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0.
+    pub fn buffered(self, n: usize) -> Stream<T::Value, E>
+            where T: Async<Error=E> {
+
+        assert!(n > 0, "the number of values in flight must be greater than 0");
+        use buffered::buffered;
+        buffered(self, n)
+    }
+
     /// Aggregate all the values of the stream by applying the given function
     /// to each value and the result of the previous application. The first
     /// iteration is seeded with the given initial value.
     ///
     /// Returns a future that will be completed with the result of the final
-    /// iteration.
-    pub fn reduce<F: Fn(U, T) -> U + Send + 'static, U: Send + 'static>(self, init: U, f: F) -> Future<U, E> {
+    /// iteration, or that fails with the error of the original stream.
+    ///
+    /// ```
+    /// use eventual::*;
+    ///
+    /// let (tx, stream) = Stream::<i32, ()>::pair();
+    ///
+    /// tx.send(1)
+    ///     .and_then(|tx| tx.send(2))
+    ///     .and_then(|tx| tx.send(3))
+    ///     .fire();
+    ///
+    /// let sum = stream.fold(0, |sum, v| sum + v);
+    /// assert_eq!(6, sum.await().unwrap());
+    /// ```
+    pub fn fold<F: FnMut(U, T) -> U + Send + 'static, U: Send + 'static>(self, init: U, mut f: F) -> Future<U, E> {
         self.reduce_async(init, move |curr, val| Ok(f(curr, val)))
     }
 
+    /// Aggregate all the values of the stream by applying the given function
+    /// to each value and the result of the previous application. The first
+    /// iteration is seeded with the given initial value.
+    ///
+    /// Returns a future that will be completed with the result of the final
+    /// iteration. See `fold`.
+    pub fn reduce<F: Fn(U, T) -> U + Send + 'static, U: Send + 'static>(self, init: U, f: F) -> Future<U, E> {
+        self.fold(init, f)
+    }
+
     /// Aggregate all the values of the stream by applying the given function
     /// to each value and the realized result of the previous application. The
     /// first iteration is seeded with the given initial value.
@@ -249,7 +587,7 @@ impl<T: Send + 'static, E: Send + 'static> Stream<T, E> {
     /// iteration.
     pub fn reduce_async<F, U, X>(self, init: X, action: F) -> Future<X, E>
             // TODO: Remove X generic, blocked on rust-lang/rust#23728
-            where F: Fn(X, T) -> U + Send + 'static,
+            where F: FnMut(X, T) -> U + Send + 'static,
                   U: Async<Value=X, Error=E>,
                   X: Send + 'static {
 
@@ -264,8 +602,8 @@ impl<T: Send + 'static, E: Send + 'static> Stream<T, E> {
         ret
     }
 
-    fn do_reduce<F, U>(self, complete: Complete<U::Value, E>, curr: U::Value, f: F)
-            where F: Fn(U::Value, T) -> U + Send + 'static,
+    fn do_reduce<F, U>(self, complete: Complete<U::Value, E>, curr: U::Value, mut f: F)
+            where F: FnMut(U::Value, T) -> U + Send + 'static,
                   U: Async<Error=E> {
 
         self.receive(move |head| {
@@ -287,15 +625,16 @@ impl<T: Send + 'static, E: Send + 'static> Stream<T, E> {
     }
 
     /// Returns a stream representing the `n` first values of the original
-    /// stream.
-    pub fn take(self, n: u64) -> Stream<T, E> {
+    /// stream. Once `n` values have been yielded, the returned stream ends and
+    /// the remainder of the original stream is canceled.
+    pub fn take(self, n: usize) -> Stream<T, E> {
         let (sender, stream) = Stream::pair();
 
         self.do_take(n, sender);
         stream
     }
 
-    fn do_take<A>(self, n: u64, sender: A) where A: Async<Value=Sender<T, E>> {
+    fn do_take<A>(self, n: usize, sender: A) where A: Async<Value=Sender<T, E>> {
         if n == 0 {
             return;
         }
@@ -316,11 +655,281 @@ impl<T: Send + 'static, E: Send + 'static> Stream<T, E> {
         });
     }
 
+    /// Returns a stream representing the values of the original stream
+    /// following the `n` first values, which are dropped.
+    pub fn skip(self, n: usize) -> Stream<T, E> {
+        let (sender, stream) = Stream::pair();
+
+        sender.receive(move |res| {
+            if let Ok(sender) = res {
+                self.do_skip(n, sender);
+            }
+        });
+
+        stream
+    }
+
+    fn do_skip(self, n: usize, sender: Sender<T, E>) {
+        if n == 0 {
+            // Done skipping, forward the remaining values
+            forward(self, sender, drop);
+            return;
+        }
+
+        self.receive(move |head| {
+            match head {
+                Ok(Some((_, rest))) => rest.do_skip(n - 1, sender),
+                Ok(None) => {}
+                Err(AsyncError::Failed(e)) => sender.fail(e),
+                Err(AsyncError::Aborted) => sender.abort(),
+            }
+        });
+    }
+
+    /// Returns a stream of the values of the original stream batched into
+    /// vecs of `size` values.
+    ///
+    /// When the original stream ends, the remaining values are yielded as a
+    /// final, shorter batch. If the original stream fails, the returned
+    /// stream fails after yielding the full batches collected before the
+    /// error and the partial batch is dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    pub fn chunks(self, size: usize) -> Stream<Vec<T>, E> {
+        assert!(size > 0, "chunk size must be greater than 0");
+
+        let (sender, stream) = Stream::pair();
+
+        sender.receive(move |res| {
+            if let Ok(sender) = res {
+                self.do_chunks(size, Vec::with_capacity(size), sender);
+            }
+        });
+
+        stream
+    }
+
+    fn do_chunks(self, size: usize, mut chunk: Vec<T>, sender: Sender<Vec<T>, E>) {
+        self.receive(move |head| {
+            match head {
+                Ok(Some((v, rest))) => {
+                    chunk.push(v);
+
+                    if chunk.len() < size {
+                        rest.do_chunks(size, chunk, sender);
+                        return;
+                    }
+
+                    sender.send(chunk).receive(move |res| {
+                        if let Ok(sender) = res {
+                            rest.do_chunks(size, Vec::with_capacity(size), sender);
+                        }
+                    });
+                }
+                Ok(None) => {
+                    if !chunk.is_empty() {
+                        sender.send(chunk);
+                    }
+                }
+                Err(AsyncError::Failed(e)) => sender.fail(e),
+                Err(AsyncError::Aborted) => sender.abort(),
+            }
+        });
+    }
+
+    /// Returns a stream of the values of the original stream, delivered at
+    /// most once per `dur`.
+    ///
+    /// Values are never dropped. Instead, a value that arrives less than
+    /// `dur` after the previous delivery is held back until `dur` has
+    /// elapsed. Since streams only produce a value once the consumer is
+    /// ready for it, no more than one value is ever held, so throttling does
+    /// not require an unbounded buffer.
+    pub fn throttle(self, dur: Duration) -> Stream<T, E> {
+        let (sender, stream) = Stream::pair();
+
+        sender.receive(move |res| {
+            if let Ok(sender) = res {
+                self.do_throttle(dur, None, sender);
+            }
+        });
+
+        stream
+    }
+
+    fn do_throttle(self, dur: Duration, last: Option<SteadyTime>, sender: Sender<T, E>) {
+        self.receive(move |head| {
+            match head {
+                Ok(Some((v, rest))) => {
+                    let wait = match last {
+                        Some(last) => dur - (SteadyTime::now() - last),
+                        None => Duration::zero(),
+                    };
+
+                    if wait > Duration::zero() {
+                        timer::shared().timeout_ms(timer::to_ms(wait)).receive(move |_| {
+                            throttle_send(v, rest, dur, sender);
+                        });
+                    } else {
+                        throttle_send(v, rest, dur, sender);
+                    }
+                }
+                Ok(None) => {}
+                Err(AsyncError::Failed(e)) => sender.fail(e),
+                Err(AsyncError::Aborted) => sender.abort(),
+            }
+        });
+    }
+
+    /// Returns a stream that only yields a value of the original stream once
+    /// `dur` has elapsed without a newer value arriving, collapsing bursts of
+    /// values into the last one.
+    ///
+    /// While waiting for the quiet period, the original stream is consumed
+    /// eagerly and each new value replaces the held one. If the original
+    /// stream ends, the held value is yielded right away. If it fails, the
+    /// held value is dropped and the returned stream fails with the same
+    /// error.
+    pub fn debounce(self, dur: Duration) -> Stream<T, E> {
+        let (sender, stream) = Stream::pair();
+
+        sender.receive(move |res| {
+            if let Ok(sender) = res {
+                self.do_debounce(dur, None, sender);
+            }
+        });
+
+        stream
+    }
+
+    fn do_debounce(self, dur: Duration, held: Option<T>, sender: Sender<T, E>) {
+        let v = match held {
+            Some(v) => v,
+            None => {
+                self.receive(move |head| {
+                    match head {
+                        Ok(Some((v, rest))) => rest.do_debounce(dur, Some(v), sender),
+                        Ok(None) => {}
+                        Err(AsyncError::Failed(e)) => sender.fail(e),
+                        Err(AsyncError::Aborted) => sender.abort(),
+                    }
+                });
+
+                return;
+            }
+        };
+
+        let quiet = timer::shared()
+            .timeout_ms(timer::to_ms(dur))
+            .map_err(|_| unreachable!());
+
+        super::select((quiet, self)).receive(move |res| {
+            match res {
+                Ok((0, (_, rest))) => {
+                    sender.send(v).receive(move |res| {
+                        if let Ok(sender) = res {
+                            rest.do_debounce(dur, None, sender);
+                        }
+                    });
+                }
+                Ok((_, (_, stream))) => {
+                    match stream.expect() {
+                        // The newer value restarts the quiet period
+                        Ok(Some((v, rest))) => rest.do_debounce(dur, Some(v), sender),
+                        Ok(None) => sender.send(v).fire(),
+                        Err(AsyncError::Failed(e)) => sender.fail(e),
+                        Err(AsyncError::Aborted) => sender.abort(),
+                    }
+                }
+                Err(AsyncError::Failed(e)) => sender.fail(e),
+                Err(AsyncError::Aborted) => sender.abort(),
+            }
+        });
+    }
+
+    /// Returns a stream representing the values of the original stream
+    /// followed by the values of `other`.
+    ///
+    /// If the original stream fails, the returned stream fails with the same
+    /// error and `other` is canceled without being consumed.
+    pub fn chain(self, other: Stream<T, E>) -> Stream<T, E> {
+        let (sender, stream) = Stream::pair();
+
+        sender.receive(move |res| {
+            if let Ok(sender) = res {
+                forward(self, sender, move |sender| forward(other, sender, drop));
+            }
+        });
+
+        stream
+    }
+
+    /// Returns a stream of pairs of the values of the original stream and
+    /// `other`, the nth value of one paired with the nth value of the other.
+    ///
+    /// A pair is yielded once both values are available, so at most one value
+    /// of either stream is held at a time. As soon as either stream ends or
+    /// fails, the returned stream ends or fails and the other stream is
+    /// canceled.
+    ///
+    /// Note that this shadows `Async::zip`, which waits for the heads of both
+    /// streams.
+    pub fn zip<U: Send + 'static>(self, other: Stream<U, E>) -> Stream<(T, U), E> {
+        super::select((self, other))
+            .and_then(|(i, (a, b))| {
+                // Whichever stream yielded first is ready, if it ended then
+                // there is no need to wait on the other one.
+                if i == 0 {
+                    match a.expect().ok().expect("selected value not successful") {
+                        None => Future::of(None),
+                        a => b.to_future().map(move |b| zip_heads(a, b)),
+                    }
+                } else {
+                    match b.expect().ok().expect("selected value not successful") {
+                        None => Future::of(None),
+                        b => a.to_future().map(move |a| zip_heads(a, b)),
+                    }
+                }
+            }).to_stream()
+    }
+
+    /// Returns a stream of the values of both the original stream and
+    /// `other`, yielded in the order that they become available.
+    ///
+    /// The returned stream ends once both streams have ended. If either
+    /// stream fails, the returned stream fails with the same error and the
+    /// other stream is canceled.
+    ///
+    /// When both streams have a value available, they take turns so that a
+    /// stream that is always ready cannot starve the other. See
+    /// `select_stream`.
+    pub fn merge<U: Send + 'static>(self, other: Stream<U, E>) -> Stream<Either<T, U>, E> {
+        use select::interleave;
+
+        let (sender, stream) = Stream::pair();
+
+        sender.receive(move |res| {
+            if let Ok(sender) = res {
+                interleave(self, other, false, sender, Either::Left, Either::Right);
+            }
+        });
+
+        stream
+    }
+
     pub fn take_while<F>(self, _f: F) -> Stream<T, E>
             where F: Fn(&T) -> bool + Send + 'static {
         unimplemented!();
     }
 
+    /// Returns a new stream yielding the values of the original stream until
+    /// `cond` completes, at which point the returned stream ends and the rest
+    /// of the original stream is canceled.
+    ///
+    /// If either `cond` or the original stream fails, the returned stream
+    /// fails with the same error.
     pub fn take_until<A>(self, cond: A) -> Stream<T, E>
             where A: Async<Error=E> {
 
@@ -355,6 +964,154 @@ impl<T: Send + 'static, E: Send + 'static> Stream<T, E> {
     }
 }
 
+impl<T: Clone + Send + 'static, E: Send + 'static> Stream<T, E> {
+    /// Returns a stream of sliding windows over the values of the original
+    /// stream. Each window holds `size` consecutive values and a new window
+    /// is yielded for every value once the first `size` values have been
+    /// received, so consecutive windows share `size - 1` values.
+    ///
+    /// Between windows, the last `size - 1` values are buffered and each
+    /// yielded window clones them, so memory use is bounded by `size` values
+    /// in addition to the windows held by the consumer. If the original
+    /// stream ends before `size` values are received, no window is yielded.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    pub fn window(self, size: usize) -> Stream<Vec<T>, E> {
+        assert!(size > 0, "window size must be greater than 0");
+
+        let (sender, stream) = Stream::pair();
+
+        sender.receive(move |res| {
+            if let Ok(sender) = res {
+                self.do_window(size, VecDeque::with_capacity(size), sender);
+            }
+        });
+
+        stream
+    }
+
+    fn do_window(self, size: usize, mut buf: VecDeque<T>, sender: Sender<Vec<T>, E>) {
+        self.receive(move |head| {
+            match head {
+                Ok(Some((v, rest))) => {
+                    buf.push_back(v);
+
+                    if buf.len() < size {
+                        rest.do_window(size, buf, sender);
+                        return;
+                    }
+
+                    let window = buf.iter().cloned().collect();
+                    buf.pop_front();
+
+                    sender.send(window).receive(move |res| {
+                        if let Ok(sender) = res {
+                            rest.do_window(size, buf, sender);
+                        }
+                    });
+                }
+                Ok(None) => {}
+                Err(AsyncError::Failed(e)) => sender.fail(e),
+                Err(AsyncError::Aborted) => sender.abort(),
+            }
+        });
+    }
+}
+
+impl<T: Send + 'static, E: Clone + Send + 'static> Stream<T, E> {
+    /// Returns two streams dividing the values of the original stream: values
+    /// for which `f` returns true go to the first stream and the others go to
+    /// the second one.
+    ///
+    /// The original stream is read as soon as either of the returned streams
+    /// needs a value, so values destined for a stream that is consumed more
+    /// slowly are buffered until it catches up, without bound. Values
+    /// destined for a stream that has been dropped are discarded, and the
+    /// original stream is canceled once both have been dropped. If the
+    /// original stream fails, both streams fail with the same error once they
+    /// have yielded their buffered values.
+    pub fn split<F>(self, f: F) -> (Stream<T, E>, Stream<T, E>)
+            where F: Fn(&T) -> bool + Send + 'static {
+        use split::split;
+        split(self, f)
+    }
+}
+
+impl<T: Send + 'static, E: Send + 'static> Stream<Stream<T, E>, E> {
+    /// Returns a stream representing the concatenation of the streams yielded
+    /// by the original stream. The next stream is only requested once the
+    /// current one has been exhausted.
+    ///
+    /// If either the original stream or one of the yielded streams fails, the
+    /// returned stream fails with the same error.
+    pub fn flatten(self) -> Stream<T, E> {
+        let (sender, stream) = Stream::pair();
+
+        sender.receive(move |res| {
+            if let Ok(sender) = res {
+                do_flatten(self, sender);
+            }
+        });
+
+        stream
+    }
+}
+
+fn throttle_send<T, E>(val: T, rest: Stream<T, E>, dur: Duration, sender: Sender<T, E>)
+        where T: Send + 'static,
+              E: Send + 'static {
+
+    let sent = SteadyTime::now();
+
+    sender.send(val).receive(move |res| {
+        if let Ok(sender) = res {
+            rest.do_throttle(dur, Some(sent), sender);
+        }
+    });
+}
+
+fn zip_heads<T, U, E>(a: Head<T, E>, b: Head<U, E>) -> Head<(T, U), E>
+        where T: Send + 'static,
+              U: Send + 'static,
+              E: Send + 'static {
+
+    match (a, b) {
+        (Some((v1, r1)), Some((v2, r2))) => Some(((v1, v2), r1.zip(r2))),
+        _ => None,
+    }
+}
+
+fn do_flatten<T: Send + 'static, E: Send + 'static>(src: Stream<Stream<T, E>, E>, dst: Sender<T, E>) {
+    src.receive(move |head| {
+        match head {
+            Ok(Some((inner, rest))) => {
+                forward(inner, dst, move |dst| do_flatten(rest, dst));
+            }
+            Ok(None) => {}
+            Err(AsyncError::Failed(e)) => dst.fail(e),
+            Err(AsyncError::Aborted) => dst.abort(),
+        }
+    });
+}
+
+// Sends all the values of `src` to `dst`, then hands `dst` back to `f`. If
+// `src` fails, `dst` is failed with the same error.
+fn forward<T, E, F>(src: Stream<T, E>, dst: Sender<T, E>, f: F)
+        where T: Send + 'static,
+              E: Send + 'static,
+              F: FnOnce(Sender<T, E>) + Send + 'static {
+
+    dst.send_all(src).receive(move |res| {
+        match res {
+            Ok(dst) => f(dst),
+            Err(AsyncError::Failed((e, dst))) => dst.fail(e),
+            Err(AsyncError::Aborted) => {}
+        }
+    });
+}
+
 impl<T: Send + 'static, E: Send + 'static> Async for Stream<T, E> {
     type Value = Head<T, E>;
     type Error = E;
@@ -401,7 +1158,31 @@ impl<T: Send + 'static, E: Send + 'static> Pair for Stream<T, E> {
 
 impl<T: Send + 'static, E: Send + 'static> fmt::Debug for Stream<T, E> {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
-        write!(fmt, "Stream<?>")
+        let mut buffered = 0;
+        let mut core = core::get(&self.core);
+
+        // Walk the values that have already been sent, the sender is alive
+        // as long as the stream has not been ended or failed.
+        let sender_alive = loop {
+            match core.consumer_peek() {
+                Some(&Ok(Some((_, ref rest)))) => {
+                    buffered += 1;
+
+                    let next = core::get(&rest.core);
+
+                    // A sender reuses the core for the rest of the stream
+                    if next.is_same(core) {
+                        break true;
+                    }
+
+                    core = next;
+                }
+                Some(_) => break false,
+                None => break true,
+            }
+        };
+
+        write!(fmt, "Stream {{ buffered: {}, sender_alive: {} }}", buffered, sender_alive)
     }
 }
 
@@ -478,7 +1259,34 @@ impl<T: Send + 'static, E: Send + 'static> Sender<T, E> {
         core::take(&mut self.core).complete(Err(AsyncError::aborted()), true);
     }
 
-    /// Send + 'static all the values in the given source
+    /// Returns a `SharedSender` that can be cloned, allowing any number of
+    /// producers to send values to the stream.
+    ///
+    /// ```
+    /// use eventual::*;
+    /// use std::thread;
+    ///
+    /// let (tx, stream) = Stream::<i32, ()>::pair();
+    /// let tx = tx.shared();
+    ///
+    /// for i in 0..3 {
+    ///     let tx = tx.clone();
+    ///     thread::spawn(move || tx.send(i).await());
+    /// }
+    ///
+    /// drop(tx);
+    ///
+    /// let mut vals: Vec<i32> = stream.iter().collect();
+    /// vals.sort();
+    /// assert_eq!([0, 1, 2], &vals[..]);
+    /// ```
+    pub fn shared(self) -> SharedSender<T, E> {
+        shared_sender::new(self)
+    }
+
+    /// Send all the values in the given source, which may be a `Future`, a
+    /// `Stream`, or any `IntoIterator`. Each value is only sent once the
+    /// consumer is ready for it.
     pub fn send_all<S: Source<Value=T>>(self, src: S) -> Future<Self, (S::Error, Self)> {
         src.send_all(self)
     }
@@ -529,11 +1337,20 @@ impl<T: Send + 'static, E: Send + 'static> Async for Sender<T, E> {
 
 impl<T: Send + 'static, E: Send + 'static> Drop for Sender<T, E> {
     fn drop(&mut self) {
+        use std::thread;
+
         if self.core.is_some() {
             debug!("Sender::drop(); cancelling future");
             // Get the core
             let mut core = core::take(&mut self.core);
-            core.complete(Ok(None), true);
+
+            // A sender dropped while unwinding did not reach the end of the
+            // stream, so don't signal that it did.
+            if thread::panicking() {
+                core.complete(Err(AsyncError::aborted()), true);
+            } else {
+                core.complete(Ok(None), true);
+            }
         }
     }
 }
@@ -544,11 +1361,50 @@ impl<T: Send + 'static, E: Send + 'static> Drop for Sender<T, E> {
  *
  */
 
+/// A `Sender` that has sent a value which has not yet been accepted by the
+/// consumer. It completes with the `Sender` once the consumer requests the
+/// next value, or fails if the stream has been dropped.
+///
+/// Awaiting it blocks the thread until the value is accepted, which lets a
+/// synchronous producer apply back-pressure without any additional thread:
+///
+/// ```
+/// use eventual::*;
+/// use std::thread;
+///
+/// let (tx, stream) = Stream::<i32, ()>::pair();
+///
+/// thread::spawn(move || {
+///     let mut tx = tx;
+///
+///     for i in 0..3 {
+///         tx = tx.send(i).await().unwrap();
+///     }
+/// });
+///
+/// let vals: Vec<i32> = stream.iter().collect();
+/// assert_eq!([0, 1, 2], &vals[..]);
+/// ```
 pub struct BusySender<T: Send + 'static, E: Send + 'static> {
     core: Option<StreamCore<T, E>>,
 }
 
 impl<T: Send + 'static, E: Send + 'static> BusySender<T, E> {
+    /// Attempts to send a value without waiting for the consumer to request
+    /// it. Succeeds only if the consumer has already requested the next
+    /// value, otherwise the value is handed back along with the sender.
+    ///
+    /// If the consumer has dropped the stream, the sender is handed back as
+    /// well and resolves with an error.
+    pub fn try_send(mut self, val: T) -> Result<BusySender<T, E>, (T, BusySender<T, E>)> {
+        let core = core::take(&mut self.core);
+
+        match core.producer_poll() {
+            Some(Ok(core)) => Ok(Sender::from_core(core).send(val)),
+            _ => Err((val, BusySender::from_core(core))),
+        }
+    }
+
     /*
      *
      * ===== Internal Helpers =====
@@ -685,6 +1541,67 @@ fn send_stream<T: Send + 'static, E: Send + 'static, E2: Send + 'static>(
     })
 }
 
+impl<I> Source for I
+        where I: IntoIterator,
+              I::IntoIter: Send + 'static,
+              I::Item: Send + 'static {
+
+    type Value = I::Item;
+    type Error = ();
+
+    fn send_all<E2: Send + 'static>(self, sender: Sender<I::Item, E2>) -> Future<Sender<I::Item, E2>, ((), Sender<I::Item, E2>)> {
+        let (tx, rx) = Future::pair();
+        send_iter(self.into_iter(), sender, tx);
+        rx
+    }
+}
+
+fn send_iter<I, E, E2>(
+    mut src: I,
+    dst: Sender<I::Item, E2>,
+    complete: Complete<Sender<I::Item, E2>, (E, Sender<I::Item, E2>)>)
+        where I: Iterator + Send + 'static,
+              I::Item: Send + 'static,
+              E: Send + 'static,
+              E2: Send + 'static {
+
+    // Don't pull the next value until the consumer is ready for it
+    dst.receive(move |res| {
+        if let Ok(dst) = res {
+            match src.next() {
+                Some(val) => {
+                    dst.send(val).receive(move |res| {
+                        if let Ok(dst) = res {
+                            send_iter(src, dst, complete);
+                        }
+                    });
+                }
+                None => complete.complete(dst),
+            }
+        }
+    });
+}
+
+fn unfold_step<T, E, S, F>(state: S, mut f: F, dst: Sender<T, E>)
+        where T: Send + 'static,
+              E: Send + 'static,
+              S: Send + 'static,
+              F: FnMut(S) -> Option<(T, S)> + Send + 'static {
+
+    // Don't compute the next value until the consumer is ready for it
+    dst.receive(move |res| {
+        if let Ok(dst) = res {
+            if let Some((val, state)) = f(state) {
+                dst.send(val).receive(move |res| {
+                    if let Ok(dst) = res {
+                        unfold_step(state, f, dst);
+                    }
+                });
+            }
+        }
+    });
+}
+
 /*
  *
  * ===== Receipt<Sender<T, E>> =====
@@ -717,12 +1634,13 @@ impl<T: Send + 'static, E: Send + 'static> Cancel<BusySender<T, E>> for Receipt<
 
 pub struct StreamIter<T: Send + 'static, E: Send + 'static> {
     core: Option<StreamCore<T, E>>,
+    // Values read ahead by `next_back`, only populated once the stream has
+    // ended
+    buffered: VecDeque<T>,
 }
 
-impl<T: Send + 'static, E: Send + 'static> Iterator for StreamIter<T, E> {
-    type Item = T;
-
-    fn next(&mut self) -> Option<T> {
+impl<T: Send + 'static, E: Send + 'static> StreamIter<T, E> {
+    fn next_value(&mut self) -> Option<T> {
         use std::mem;
 
         match core::get_mut(&mut self.core).consumer_await() {
@@ -739,6 +1657,30 @@ impl<T: Send + 'static, E: Send + 'static> Iterator for StreamIter<T, E> {
     }
 }
 
+impl<T: Send + 'static, E: Send + 'static> Iterator for StreamIter<T, E> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.core.is_some() {
+            return self.next_value();
+        }
+
+        self.buffered.pop_front()
+    }
+}
+
+impl<T: Send + 'static, E: Send + 'static> DoubleEndedIterator for StreamIter<T, E> {
+    fn next_back(&mut self) -> Option<T> {
+        while self.core.is_some() {
+            if let Some(val) = self.next_value() {
+                self.buffered.push_back(val);
+            }
+        }
+
+        self.buffered.pop_back()
+    }
+}
+
 impl<T: Send + 'static, E: Send + 'static> Drop for StreamIter<T, E> {
     fn drop(&mut self) {
         if self.core.is_some() {
@@ -747,6 +1689,106 @@ impl<T: Send + 'static, E: Send + 'static> Drop for StreamIter<T, E> {
     }
 }
 
+/*
+ *
+ * ===== PeekableStream =====
+ *
+ */
+
+/// A stream that can buffer its next value so that it can be looked at
+/// without being consumed. See `Stream::peekable`.
+pub struct PeekableStream<T: Send + 'static, E: Send + 'static> {
+    state: Peeked<T, E>,
+}
+
+enum Peeked<T: Send + 'static, E: Send + 'static> {
+    // The next value has not been requested yet
+    No(Stream<T, E>),
+    // The next value is buffered
+    Value(T, Stream<T, E>),
+    // The stream has ended
+    End,
+}
+
+impl<T: Send + 'static, E: Send + 'static> PeekableStream<T, E> {
+    /// Returns a future that completes with the stream once its next value
+    /// is buffered, which can then be inspected with `peeked`.
+    ///
+    /// If the next value is already buffered, the returned future is
+    /// immediately complete. If the stream fails, the returned future fails
+    /// with the same error.
+    ///
+    /// ```
+    /// use eventual::*;
+    ///
+    /// let stream = Future::<_, ()>::of(Some((1, Stream::empty()))).to_stream();
+    /// let peekable = stream.peekable().peek().await().unwrap();
+    ///
+    /// assert_eq!(Some(&1), peekable.peeked());
+    /// assert_eq!(Ok(vec![1]), peekable.into_stream().collect().await());
+    /// ```
+    pub fn peek(self) -> Future<PeekableStream<T, E>, E> {
+        match self.state {
+            Peeked::No(stream) => {
+                Async::map(stream, |head| {
+                    let state = match head {
+                        Some((v, rest)) => Peeked::Value(v, rest),
+                        None => Peeked::End,
+                    };
+
+                    PeekableStream { state: state }
+                })
+            }
+            state => Future::of(PeekableStream { state: state }),
+        }
+    }
+
+    /// Returns a reference to the buffered next value. Returns `None` if
+    /// `peek` has not completed yet or if the stream has ended.
+    pub fn peeked(&self) -> Option<&T> {
+        match self.state {
+            Peeked::Value(ref v, _) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// Returns true if `peek` has completed and found that the stream has
+    /// ended.
+    pub fn is_done(&self) -> bool {
+        match self.state {
+            Peeked::End => true,
+            _ => false,
+        }
+    }
+
+    /// Returns a future that completes with the next value of the stream,
+    /// consuming the buffered value if there is one, along with the rest of
+    /// the stream.
+    pub fn next(self) -> Future<Option<(T, PeekableStream<T, E>)>, E> {
+        match self.state {
+            Peeked::No(stream) => Async::map(stream, |head| head.map(|(v, rest)| (v, rest.peekable()))),
+            Peeked::Value(v, rest) => Future::of(Some((v, rest.peekable()))),
+            Peeked::End => Future::of(None),
+        }
+    }
+
+    /// Returns the underlying stream, starting with the buffered value if
+    /// there is one.
+    pub fn into_stream(self) -> Stream<T, E> {
+        match self.state {
+            Peeked::No(stream) => stream,
+            Peeked::Value(v, rest) => Future::of(Some((v, rest))).to_stream(),
+            Peeked::End => Stream::empty(),
+        }
+    }
+}
+
+impl<T: Send + 'static, E: Send + 'static> fmt::Debug for PeekableStream<T, E> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "PeekableStream {{ ... }}")
+    }
+}
+
 pub fn from_core<T: Send + 'static, E: Send + 'static>(core: StreamCore<T, E>) -> Stream<T, E> {
     Stream { core: Some(core) }
 }
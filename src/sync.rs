@@ -0,0 +1,118 @@
+use {Future, Semaphore, Permit};
+use std::cell::{Cell, UnsafeCell};
+use std::marker::PhantomData;
+use std::ops::{Deref, DerefMut};
+use std::sync::Arc;
+use std::fmt;
+
+/// A mutual exclusion primitive for async code. Instead of blocking,
+/// `lock` returns a future that completes with a guard once the lock is
+/// available.
+///
+/// Lock requests are granted one at a time, in the order they were made.
+/// Clones of an `AsyncMutex` share the same lock and value.
+///
+/// ```
+/// use eventual::*;
+///
+/// let mutex = AsyncMutex::new(0);
+///
+/// let mut guard = mutex.lock().await().unwrap();
+/// let next = mutex.lock();
+///
+/// *guard += 1;
+/// assert!(!next.is_ready());
+///
+/// drop(guard);
+/// assert_eq!(1, *next.await().unwrap());
+/// ```
+pub struct AsyncMutex<T: Send + 'static> {
+    inner: Arc<Inner<T>>,
+}
+
+struct Inner<T> {
+    sem: Semaphore,
+    value: UnsafeCell<T>,
+}
+
+// Access to the value is guarded by the semaphore
+unsafe impl<T: Send> Send for Inner<T> {}
+unsafe impl<T: Send> Sync for Inner<T> {}
+
+impl<T: Send + 'static> AsyncMutex<T> {
+    /// Returns a new mutex guarding the given value.
+    pub fn new(value: T) -> AsyncMutex<T> {
+        AsyncMutex {
+            inner: Arc::new(Inner {
+                sem: Semaphore::new(1),
+                value: UnsafeCell::new(value),
+            }),
+        }
+    }
+
+    /// Returns a future that completes with a guard once the lock has been
+    /// acquired. Dropping the guard releases the lock to the next request.
+    ///
+    /// Dropping the future before it completes gives up its place in the
+    /// queue.
+    pub fn lock(&self) -> Future<AsyncMutexGuard<T>, ()> {
+        let inner = self.inner.clone();
+
+        self.inner.sem.acquire()
+            .map(move |permit| AsyncMutexGuard { inner: inner, _permit: permit, marker: PhantomData })
+            // The semaphore is never closed
+            .map_err(|_| ())
+    }
+
+    /// Returns a guard if the lock can be acquired right away.
+    pub fn try_lock(&self) -> Option<AsyncMutexGuard<T>> {
+        self.inner.sem.try_acquire().map(|permit| {
+            AsyncMutexGuard { inner: self.inner.clone(), _permit: permit, marker: PhantomData }
+        })
+    }
+}
+
+impl<T: Send + 'static> Clone for AsyncMutex<T> {
+    fn clone(&self) -> AsyncMutex<T> {
+        AsyncMutex { inner: self.inner.clone() }
+    }
+}
+
+impl<T: Send + 'static> fmt::Debug for AsyncMutex<T> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "AsyncMutex {{ ... }}")
+    }
+}
+
+/// Provides access to the value of an `AsyncMutex`. The lock is released
+/// when the guard is dropped.
+pub struct AsyncMutexGuard<T: Send + 'static> {
+    inner: Arc<Inner<T>>,
+    // Released on drop, after which the next lock request is granted
+    _permit: Permit,
+    // The guard hands out references to the value, so it must only be shared
+    // between threads if the value can be
+    marker: PhantomData<Cell<T>>,
+}
+
+unsafe impl<T: Send + Sync + 'static> Sync for AsyncMutexGuard<T> {}
+
+impl<T: Send + 'static> Deref for AsyncMutexGuard<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { &*self.inner.value.get() }
+    }
+}
+
+impl<T: Send + 'static> DerefMut for AsyncMutexGuard<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { &mut *self.inner.value.get() }
+    }
+}
+
+impl<T: Send + fmt::Debug + 'static> fmt::Debug for AsyncMutexGuard<T> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "AsyncMutexGuard({:?})", &**self)
+    }
+}
@@ -1,6 +1,9 @@
-use {Async, Future, Stream, Sender};
+use {Async, AsyncError, Cancel, Future, Stream, Sender};
 use syncbox::ScheduledThreadPool;
 use time::{SteadyTime, Duration};
+use std::error::Error;
+use std::sync::Once;
+use std::{fmt, u32};
 
 /// Provides timeouts as a `Future` and periodic ticks as a `Stream`.
 pub struct Timer {
@@ -44,11 +47,95 @@ impl Timer {
         rx
     }
 
+    /// Returns a `Future` that will be completed once `dur` has elapsed.
+    ///
+    /// The future fails with `TimerError::InvalidDuration` if `dur` is
+    /// negative or exceeds the millisecond resolution of the timer.
+    pub fn one_shot(&self, dur: Duration) -> Future<(), TimerError> {
+        if dur < Duration::zero() || dur.num_milliseconds() > u32::MAX as i64 {
+            return Future::error(TimerError::InvalidDuration);
+        }
+
+        self.timeout_ms(to_ms(dur)).map_err(|_| unreachable!())
+    }
+
+    /// Returns a `Future` that completes with the result of `async` once both
+    /// `async` has completed and `dur` has elapsed.
+    ///
+    /// Unlike `defer`, which delays starting a computation, `async` is started
+    /// right away and only its result is held back. Errors are held back as
+    /// well.
+    ///
+    /// Dropping the returned future before its value is requested cancels
+    /// `async`. If interest is lost after that, the result of `async` is
+    /// discarded without waiting on the deadline.
+    pub fn delay<A: Async>(&self, async: A, dur: Duration) -> Future<A::Value, A::Error> {
+        let (complete, ret) = Future::pair();
+        let (tx, source) = Future::pair();
+        let deadline = self.timeout_ms(to_ms(dur));
+
+        let cancel = async.ready(move |async| {
+            match async.expect() {
+                Ok(v) => tx.complete(v),
+                Err(AsyncError::Failed(e)) => tx.fail(e),
+                Err(AsyncError::Aborted) => drop(tx),
+            }
+        });
+
+        complete.receive(move |res| {
+            match res {
+                Ok(complete) => {
+                    source.receive(move |res| {
+                        // The consumer lost interest, the deadline is dropped
+                        // without being scheduled
+                        if complete.is_cancelled() {
+                            return;
+                        }
+
+                        deadline.receive(move |_| {
+                            match res {
+                                Ok(v) => complete.complete(v),
+                                Err(AsyncError::Failed(e)) => complete.fail(e),
+                                Err(AsyncError::Aborted) => drop(complete),
+                            }
+                        });
+                    });
+                }
+                // The consumer is gone, dropping `async` cancels it
+                Err(_) => drop(cancel.cancel()),
+            }
+        });
+
+        ret
+    }
+
     /// Return a `Stream` with values realized every `ms` milliseconds.
     pub fn interval_ms(&self, ms: u32) -> Stream<(), ()> {
+        self.start_interval(Duration::milliseconds(ms as i64))
+    }
+
+    /// Returns a `Stream` that yields a value every `dur`.
+    ///
+    /// Each tick is scheduled relative to when the previous tick was due
+    /// rather than when it was delivered, so the stream does not drift when
+    /// the consumer is slow. The stream never completes on its own; dropping
+    /// it stops the ticks.
+    ///
+    /// The stream fails with `TimerError::InvalidDuration` if `dur` is not
+    /// positive or exceeds the millisecond resolution of the timer.
+    pub fn interval(&self, dur: Duration) -> Stream<(), TimerError> {
+        if dur <= Duration::zero() || dur.num_milliseconds() > u32::MAX as i64 {
+            let (tx, rx) = Stream::pair();
+            tx.fail(TimerError::InvalidDuration);
+            return rx;
+        }
+
+        self.start_interval(dur)
+    }
+
+    fn start_interval<E: Send + 'static>(&self, interval: Duration) -> Stream<(), E> {
         let (tx, rx) = Stream::pair();
         let pool = self.pool.clone();
-        let interval = Duration::milliseconds(ms as i64);
         let next = SteadyTime::now() + interval;
 
         do_interval(pool, tx, next, interval);
@@ -58,20 +145,22 @@ impl Timer {
 }
 
 /// Processes the interval stream
-fn do_interval<S>(pool: ScheduledThreadPool,
-                  sender: S,
-                  next: SteadyTime,
-                  interval: Duration)
-        where S: Async<Value=Sender<(), ()>> {
+fn do_interval<S, E>(pool: ScheduledThreadPool,
+                     sender: S,
+                     next: SteadyTime,
+                     interval: Duration)
+        where S: Async<Value=Sender<(), E>>,
+              E: Send + 'static {
 
     sender.receive(move |res| {
         if let Ok(sender) = res {
             let now = SteadyTime::now();
-            let delay = next - now;
+            // A late tick has a negative delay and is sent right away
+            let delay = to_ms(next - now);
             let next = next + interval;
             let pool2 = pool.clone();
 
-            pool.schedule_ms(delay.num_milliseconds() as u32, move || {
+            pool.schedule_ms(delay, move || {
                 let busy = sender.send(());
                 do_interval(pool2, busy, next, interval);
             });
@@ -84,3 +173,102 @@ impl Clone for Timer {
         Timer { pool: self.pool.clone() }
     }
 }
+
+/// Returns a handle to the timer shared by combinators that need to schedule
+/// work without being handed a `Timer`, such as `Async::timeout`.
+pub fn shared() -> Timer {
+    static INIT: Once = Once::new();
+    static mut SHARED: *const Timer = 0 as *const Timer;
+
+    unsafe {
+        INIT.call_once(|| {
+            SHARED = Box::into_raw(Box::new(Timer::new()));
+        });
+
+        (*SHARED).clone()
+    }
+}
+
+/// Returns a `Future` that will be completed once `dur` has elapsed, using
+/// the shared timer. See `Timer::one_shot`.
+///
+/// ```
+/// # extern crate eventual;
+/// # extern crate time;
+/// use eventual::*;
+/// use time::Duration;
+/// # fn main() {
+///
+/// let res = sleep(Duration::milliseconds(10)).and_then(|_| Ok("done"));
+/// assert_eq!(Ok("done"), res.await());
+/// # }
+/// ```
+pub fn sleep(dur: Duration) -> Future<(), TimerError> {
+    shared().one_shot(dur)
+}
+
+/// Converts a duration to the millisecond resolution used by the scheduler,
+/// clamping negative durations to zero.
+pub fn to_ms(dur: Duration) -> u32 {
+    let ms = dur.num_milliseconds();
+
+    if ms <= 0 {
+        0
+    } else if ms >= u32::MAX as i64 {
+        u32::MAX
+    } else {
+        ms as u32
+    }
+}
+
+/*
+ *
+ * ===== TimedOut =====
+ *
+ */
+
+/// The error returned when an async computation does not complete before its
+/// deadline.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct TimedOut;
+
+impl Error for TimedOut {
+    fn description(&self) -> &str {
+        "timed out"
+    }
+}
+
+impl fmt::Display for TimedOut {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "timed out")
+    }
+}
+
+/*
+ *
+ * ===== TimerError =====
+ *
+ */
+
+/// The error returned by `Timer` streams that cannot be scheduled.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum TimerError {
+    /// The requested duration is not positive or is too long to schedule.
+    InvalidDuration,
+}
+
+impl Error for TimerError {
+    fn description(&self) -> &str {
+        match *self {
+            TimerError::InvalidDuration => "invalid timer duration",
+        }
+    }
+}
+
+impl fmt::Display for TimerError {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            TimerError::InvalidDuration => write!(fmt, "invalid timer duration"),
+        }
+    }
+}
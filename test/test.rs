@@ -26,39 +26,118 @@ extern crate log;
  * Last ported test: test_producer_fail_before_consumer_take
  */
 
+// == AsyncError tests ==
+mod test_async_error;
+
+// == Broadcast tests ==
+mod test_broadcast;
+
+// == Cache tests ==
+mod test_cache;
+
+// == Context tests ==
+mod test_context;
+
+// == Erased tests ==
+mod test_erased;
+
 // == Future tests ==
 mod test_future_and;
 mod test_future_await;
 mod test_future_cancel;
+mod test_future_debug;
+mod test_future_fail_with;
+mod test_future_flatten;
+mod test_future_flatten_result;
+mod test_future_from_result;
+mod test_future_gate;
+mod test_future_inspect;
 mod test_future_map;
 mod test_future_map_err;
+mod test_future_never;
+mod test_future_on_complete;
 mod test_future_or;
+mod test_future_pair_with_timeout;
+mod test_future_panic_aware;
+mod test_future_promise;
+mod test_future_race;
 mod test_future_receive;
+mod test_future_recover;
+mod test_future_shared;
+mod test_future_then;
+mod test_future_timeout;
+mod test_future_zip;
 
 // == Join tests ==
 mod test_join;
 mod test_run;
 
+// == Latch tests ==
+mod test_latch;
+
+// == Notify tests ==
+mod test_notify;
+
+// == Retry tests ==
+mod test_retry;
+
 // == Select tests ==
 mod test_select;
+mod test_select_any;
+mod test_select_ok;
+mod test_select_stream;
+
+// == Semaphore tests ==
+mod test_semaphore;
 
 // == Sequence tests ==
 mod test_sequence;
 
 // == Stream tests ==
 mod test_stream_await;
+mod test_stream_buffer_unordered;
+mod test_stream_buffered;
 mod test_stream_cancel;
+mod test_stream_chain;
+mod test_stream_channel;
+mod test_stream_chunks;
 mod test_stream_collect;
+mod test_stream_debounce;
+mod test_stream_debug;
 mod test_stream_each;
+mod test_stream_enumerate;
 mod test_stream_filter;
+mod test_stream_first;
+mod test_stream_flatten;
+mod test_stream_for_each;
+mod test_stream_from_iter;
+mod test_stream_from_result;
 mod test_stream_iter;
+mod test_stream_last;
 mod test_stream_map;
 mod test_stream_map_err;
+mod test_stream_merge;
+mod test_stream_once;
+mod test_stream_peekable;
 mod test_stream_process;
 mod test_stream_receive;
+mod test_stream_recover_item;
 mod test_stream_reduce;
+mod test_stream_repeat;
+mod test_stream_scan;
 mod test_stream_send_all;
+mod test_stream_shared_sender;
+mod test_stream_skip;
+mod test_stream_split;
 mod test_stream_take;
+mod test_stream_throttle;
+mod test_stream_try_send;
+mod test_stream_unfold;
+mod test_stream_window;
+mod test_stream_zip;
+
+// == Sync tests ==
+mod test_sync;
 
 // == Timer tests ==
 mod test_timer;
@@ -0,0 +1,64 @@
+use eventual::*;
+
+#[test]
+pub fn test_async_error_map_failed() {
+    let err = AsyncError::failed(123).map(|e| e.to_string());
+    assert_eq!(AsyncError::Failed("123".to_string()), err);
+}
+
+#[test]
+pub fn test_async_error_map_aborted() {
+    let err = AsyncError::<i32>::aborted().map(|_| -> &'static str { panic!("nope") });
+    assert_eq!(AsyncError::Aborted, err);
+}
+
+#[test]
+pub fn test_async_error_and_then() {
+    let err = AsyncError::failed(123).and_then(|e| AsyncError::failed(e + 1));
+    assert_eq!(AsyncError::Failed(124), err);
+
+    let err = AsyncError::failed(123).and_then(|_| AsyncError::<i32>::aborted());
+    assert_eq!(AsyncError::Aborted, err);
+}
+
+#[test]
+pub fn test_async_error_and_then_aborted() {
+    let err = AsyncError::<i32>::aborted().and_then(|_| -> AsyncError<i32> { panic!("nope") });
+    assert_eq!(AsyncError::Aborted, err);
+}
+
+#[test]
+pub fn test_async_error_map_in_result() {
+    let res: AsyncResult<(), i32> = Future::error(123).await();
+    let res = res.map_err(|e| e.map(|code| format!("code {}", code)));
+
+    assert_eq!(Err(AsyncError::Failed("code 123".to_string())), res);
+}
+
+#[test]
+pub fn test_async_error_from_error() {
+    fn parse(s: &str) -> AsyncResult<i32, ::std::num::ParseIntError> {
+        let v: i32 = s.parse()?;
+        Ok(v * 2)
+    }
+
+    assert_eq!(Ok(246), parse("123"));
+    assert!(parse("nope").unwrap_err().is_failed());
+}
+
+#[test]
+pub fn test_async_error_from_in_receive() {
+    fn double(res: AsyncResult<i32, &'static str>) -> AsyncResult<i32, &'static str> {
+        let v = res?;
+
+        if v < 0 {
+            Err("negative")?;
+        }
+
+        Ok(v * 2)
+    }
+
+    assert_eq!(Ok(246), double(Future::of(123).await()));
+    assert_eq!(Err(AsyncError::Failed("negative")), double(Future::of(-1).await()));
+    assert_eq!(Err(AsyncError::Aborted), double(Err(AsyncError::Aborted)));
+}
@@ -0,0 +1,146 @@
+use eventual::*;
+use std::sync::mpsc::{channel, Receiver};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Error {
+    Failed,
+    Lagged,
+}
+
+impl From<Lagged> for Error {
+    fn from(_: Lagged) -> Error {
+        Error::Lagged
+    }
+}
+
+// Collects the stream eagerly, unlike `Stream::collect` which waits for
+// interest before consuming the stream.
+fn collect<T: Send, E: Send>(stream: Stream<T, E>) -> Receiver<AsyncResult<Vec<T>, E>> {
+    let (tx, rx) = channel();
+    stream.collect().receive(move |res| tx.send(res).unwrap());
+    rx
+}
+
+#[test]
+pub fn test_broadcast_to_all_subscribers() {
+    let (tx, subscriber) = broadcast::<i32, ()>();
+
+    let a = collect(subscriber.subscribe());
+    let b = collect(subscriber.subscribe());
+
+    tx.send(1).await().unwrap();
+    tx.send(2).await().unwrap();
+    drop(tx);
+
+    assert_eq!(Ok(vec![1, 2]), a.recv().unwrap());
+    assert_eq!(Ok(vec![1, 2]), b.recv().unwrap());
+}
+
+#[test]
+pub fn test_broadcast_late_subscriber() {
+    let (tx, subscriber) = broadcast::<i32, ()>();
+
+    let a = subscriber.subscribe().collect();
+    tx.send(1).fire();
+
+    let b = subscriber.subscribe().collect();
+    tx.send(2).fire();
+    drop(tx);
+
+    assert_eq!(Ok(vec![1, 2]), a.await());
+    assert_eq!(Ok(vec![2]), b.await());
+
+    // Subscribing after the sender is gone yields an empty stream
+    assert_eq!(Ok(vec![]), subscriber.subscribe().collect().await());
+}
+
+#[test]
+pub fn test_broadcast_back_pressure() {
+    let (tx, subscriber) = broadcast::<i32, ()>();
+    let mut vals = subscriber.subscribe().iter();
+
+    // The first value fits in the subscriber's slot
+    assert!(tx.send(1).is_ready());
+
+    // The second one waits for the subscriber to take the first
+    let (done_tx, done_rx) = channel();
+    tx.send(2).receive(move |res| done_tx.send(res).unwrap());
+    assert!(done_rx.try_recv().is_err());
+
+    // Taking the first value does not request the next one yet
+    assert_eq!(Some(1), vals.next());
+    assert!(done_rx.try_recv().is_err());
+
+    assert_eq!(Some(2), vals.next());
+    assert_eq!(Ok(Ok(())), done_rx.recv());
+
+    drop(tx);
+    assert_eq!(None, vals.next());
+}
+
+#[test]
+pub fn test_broadcast_dropped_subscriber_releases_sender() {
+    let (tx, subscriber) = broadcast::<i32, ()>();
+    let slow = subscriber.subscribe();
+    let fast = collect(subscriber.subscribe());
+
+    tx.send(1).fire();
+
+    let (done_tx, done_rx) = channel();
+    tx.send(2).receive(move |res| done_tx.send(res).unwrap());
+
+    drop(slow);
+    assert_eq!(Ok(Ok(())), done_rx.recv());
+
+    drop(tx);
+    assert_eq!(Ok(vec![1, 2]), fast.recv().unwrap());
+}
+
+#[test]
+pub fn test_broadcast_fail() {
+    let (tx, subscriber) = broadcast::<i32, &'static str>();
+    let mut vals = subscriber.subscribe();
+
+    tx.send(1).fire();
+    tx.fail("nope");
+
+    match vals.await() {
+        Ok(Some((v, rest))) => {
+            assert_eq!(1, v);
+            vals = rest;
+        }
+        _ => panic!("unexpected result"),
+    }
+
+    assert_eq!(Err(AsyncError::Failed("nope")), vals.await().map(|_| ()));
+    assert_eq!(Err(AsyncError::Failed("nope")), subscriber.subscribe().collect().await());
+}
+
+#[test]
+pub fn test_broadcast_lagged_subscriber() {
+    let (tx, subscriber) = broadcast_lagged::<i32, Error>(2);
+    let slow = subscriber.subscribe();
+    let fast = collect(subscriber.subscribe());
+
+    // Sending never waits, the slow subscriber falls behind
+    for i in 0..5 {
+        assert!(tx.send(i).is_ready());
+    }
+
+    drop(tx);
+
+    assert_eq!(Ok(vec![0, 1, 2, 3, 4]), fast.recv().unwrap());
+    assert_eq!(Err(AsyncError::Failed(Error::Lagged)), slow.collect().await());
+}
+
+#[test]
+pub fn test_broadcast_lagged_subscriber_keeping_up() {
+    let (tx, subscriber) = broadcast_lagged::<i32, Error>(2);
+    let vals = subscriber.subscribe().collect();
+
+    tx.send(1).fire();
+    tx.send(2).fire();
+    tx.fail(Error::Failed);
+
+    assert_eq!(Err(AsyncError::Failed(Error::Failed)), vals.await());
+}
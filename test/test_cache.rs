@@ -0,0 +1,93 @@
+use eventual::*;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use super::{spawn, sleep_ms};
+
+#[test]
+pub fn test_cache_reuses_future() {
+    let store = Arc::new(Mutex::new(HashMap::new()));
+    let calls = Arc::new(AtomicUsize::new(0));
+
+    for _ in 0..3 {
+        let calls = calls.clone();
+
+        let f = cache(1, store.clone(), move || {
+            calls.fetch_add(1, Ordering::SeqCst);
+            Future::<&'static str, ()>::of("one")
+        });
+
+        assert_eq!(Ok("one"), f.await());
+    }
+
+    assert_eq!(1, calls.load(Ordering::SeqCst));
+}
+
+#[test]
+pub fn test_cache_distinct_keys() {
+    let store = Arc::new(Mutex::new(HashMap::new()));
+
+    let a = cache("a", store.clone(), || Future::<i32, ()>::of(1));
+    let b = cache("b", store.clone(), || Future::<i32, ()>::of(2));
+
+    assert_eq!(Ok(1), a.await());
+    assert_eq!(Ok(2), b.await());
+    assert_eq!(2, store.lock().unwrap().len());
+}
+
+#[test]
+pub fn test_cache_pending_future_is_shared() {
+    let store = Arc::new(Mutex::new(HashMap::new()));
+    let (c, f) = Future::<i32, ()>::pair();
+
+    let a = cache("key", store.clone(), move || f);
+    let b = cache("key", store.clone(), || panic!("computed twice"));
+
+    spawn(move || {
+        sleep_ms(50);
+        c.complete(123);
+    });
+
+    assert_eq!(Ok(123), a.await());
+    assert_eq!(Ok(123), b.await());
+}
+
+#[test]
+pub fn test_cache_concurrent_calls_compute_once() {
+    let store = Arc::new(Mutex::new(HashMap::new()));
+    let calls = Arc::new(AtomicUsize::new(0));
+
+    let handles: Vec<_> = (0..8).map(|_| {
+        let store = store.clone();
+        let calls = calls.clone();
+
+        ::std::thread::spawn(move || {
+            cache("key", store, move || {
+                calls.fetch_add(1, Ordering::SeqCst);
+                Future::<i32, ()>::of(7)
+            }).await()
+        })
+    }).collect();
+
+    for handle in handles {
+        assert_eq!(Ok(7), handle.join().unwrap());
+    }
+
+    assert_eq!(1, calls.load(Ordering::SeqCst));
+}
+
+#[test]
+pub fn test_cache_keeps_errors() {
+    let store = Arc::new(Mutex::new(HashMap::new()));
+
+    let a = cache(1, store.clone(), || Future::<i32, &'static str>::error("nope"));
+    assert_eq!(Err(AsyncError::failed("nope")), a.await());
+
+    let b = cache(1, store.clone(), || Future::of(1));
+    assert_eq!(Err(AsyncError::failed("nope")), b.await());
+
+    store.lock().unwrap().remove(&1);
+
+    let c = cache(1, store.clone(), || Future::of(1));
+    assert_eq!(Ok(1), c.await());
+}
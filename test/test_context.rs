@@ -0,0 +1,55 @@
+use eventual::*;
+use super::{spawn, sleep_ms};
+
+#[derive(Clone, Debug, PartialEq)]
+struct Trace(u64);
+
+#[test]
+pub fn test_context_carried_through_chain() {
+    let (c, f) = Future::<i32, ()>::pair();
+
+    let f = f.with_context(Trace(7))
+        .and_then_ctx(|ctx, v| Ok((ctx.0, v)))
+        .map_ctx(|ctx, (id, v)| {
+            assert_eq!(Trace(7), *ctx);
+            id as i32 + v
+        });
+
+    assert_eq!(Trace(7), *f.context());
+
+    spawn(move || {
+        sleep_ms(50);
+        c.complete(3);
+    });
+
+    assert_eq!(Ok(10), f.await());
+}
+
+#[test]
+pub fn test_context_is_async() {
+    let f = Future::<i32, ()>::of(1).with_context(Trace(1));
+
+    // The wrapper can be used anywhere an async value is expected
+    let joined = join((f, Future::of(2)));
+    assert_eq!(Ok((1, 2)), joined.await());
+}
+
+#[test]
+pub fn test_context_failure_skips_callbacks() {
+    let f = Future::<i32, &'static str>::error("nope").with_context(Trace(1))
+        .map_ctx(|_, _| -> i32 { panic!("should not be invoked") });
+
+    assert_eq!(Err(AsyncError::failed("nope")), f.await());
+}
+
+#[test]
+pub fn test_context_cancel_returns_wrapper() {
+    let (_c, f) = Future::<i32, ()>::pair();
+    let f = f.with_context(Trace(3));
+
+    let cancel = f.ready(|_| panic!("should not be invoked"));
+    let f = cancel.cancel().expect("cancelable");
+
+    assert_eq!(Trace(3), *f.context());
+    assert!(!f.into_inner().is_ready());
+}
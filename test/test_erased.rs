@@ -0,0 +1,74 @@
+use eventual::*;
+use std::sync::mpsc::channel;
+
+#[test]
+pub fn test_erased_mixed_collection() {
+    let (c, f) = Future::<i32, ()>::pair();
+
+    let asyncs = vec![
+        f.map(|v| v + 1).erase(),
+        Future::of(10).erase(),
+        Ok::<i32, ()>(20).erase(),
+    ];
+
+    let res = join(asyncs);
+    c.complete(1);
+
+    assert_eq!(Ok(vec![2, 10, 20]), res.await());
+}
+
+#[test]
+pub fn test_erased_poll() {
+    let (c, f) = Future::<i32, ()>::pair();
+    let f = f.erase();
+
+    assert!(!f.is_ready());
+    let f = f.poll().unwrap_err();
+
+    c.complete(123);
+
+    assert!(f.is_ready());
+    assert_eq!(Ok(123), f.poll().unwrap());
+}
+
+#[test]
+pub fn test_erased_failure() {
+    let f = Future::<i32, &'static str>::error("nope").erase();
+
+    assert!(f.is_err());
+    assert_eq!(Err(AsyncError::Failed("nope")), f.await());
+}
+
+#[test]
+pub fn test_erased_cancel() {
+    let (c, f) = Future::<i32, ()>::pair();
+
+    let cancel = f.erase().ready(|_| panic!("nope"));
+    let f = cancel.cancel().expect("cancel failed");
+
+    c.complete(123);
+    assert_eq!(Ok(123), f.await());
+}
+
+#[test]
+pub fn test_erased_cancel_after_complete() {
+    let (c, f) = Future::<i32, ()>::pair();
+    let (tx, rx) = channel();
+
+    let cancel = f.erase().ready(move |f| tx.send(f.expect()).unwrap());
+
+    c.complete(123);
+    assert!(cancel.cancel().is_none());
+    assert_eq!(Ok(123), rx.recv().unwrap());
+}
+
+#[test]
+pub fn test_boxed_mixed_collection() {
+    let asyncs: Vec<BoxedAsync<i32, String>> = vec![
+        Future::of(1).boxed(),
+        Future::<i32, String>::of(1).map(|v| v + 1).boxed(),
+        Ok::<i32, String>(3).boxed(),
+    ];
+
+    assert_eq!(Ok(vec![1, 2, 3]), join(asyncs).await());
+}
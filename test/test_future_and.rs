@@ -29,3 +29,21 @@ pub fn test_and_success_async() {
     assert_eq!("first", rx.recv().unwrap());
     assert_eq!("second", rx.recv().unwrap());
 }
+
+#[test]
+pub fn test_and_return_success() {
+    let (c, f) = Future::<i32, ()>::pair();
+    let res = f.and_return("done");
+
+    c.complete(123);
+    assert_eq!(Ok("done"), res.await());
+}
+
+#[test]
+pub fn test_and_return_failure() {
+    let (c, f) = Future::<i32, &'static str>::pair();
+    let res = f.and_return("done");
+
+    c.fail("nope");
+    assert_eq!(Err(AsyncError::Failed("nope")), res.await());
+}
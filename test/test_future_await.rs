@@ -4,6 +4,7 @@ use std::sync::mpsc::channel;
 use std::sync::Arc;
 use std::sync::atomic::AtomicBool;
 use std::sync::atomic::Ordering::Relaxed;
+use time::{Duration, SteadyTime};
 
 #[test]
 pub fn test_complete_before_await() {
@@ -140,3 +141,140 @@ pub fn test_producer_drops_after_consumer_await() {
     let err = f.await().unwrap_err();
     assert!(err.is_aborted());
 }
+
+/*
+ *
+ * ===== Async::try_await() =====
+ *
+ */
+
+#[test]
+pub fn test_try_await_pending() {
+    let (c, f) = Future::<i32, ()>::pair();
+
+    assert!(f.try_await().is_none());
+
+    // The pending future has been dropped
+    assert!(c.is_err());
+}
+
+#[test]
+pub fn test_try_await_completed() {
+    let f = Future::<i32, ()>::of(123);
+    assert_eq!(Some(Ok(123)), f.try_await());
+
+    let f = Future::<i32, &'static str>::error("nope");
+    assert_eq!(Some(Err(AsyncError::Failed("nope"))), f.try_await());
+}
+
+#[test]
+pub fn test_try_await_aborted() {
+    let (c, f) = Future::<i32, ()>::pair();
+    drop(c);
+
+    assert_eq!(Some(Err(AsyncError::Aborted)), f.try_await());
+}
+
+#[test]
+pub fn test_try_await_stream() {
+    let (tx, rx) = Stream::<i32, ()>::pair();
+    drop(tx);
+
+    match rx.try_await() {
+        Some(Ok(None)) => {}
+        _ => panic!("unexpected value"),
+    }
+}
+
+/*
+ *
+ * ===== Async::wait_timeout() =====
+ *
+ */
+
+#[test]
+pub fn test_wait_timeout_complete_future() {
+    let f = Future::<i32, ()>::of(123);
+    assert_eq!(Ok(123), f.wait_timeout(Duration::milliseconds(10)).unwrap());
+}
+
+#[test]
+pub fn test_wait_timeout_completes_in_time() {
+    let (c, f) = Future::<i32, ()>::pair();
+
+    spawn(move || {
+        sleep_ms(20);
+        c.complete(123);
+    });
+
+    assert_eq!(Ok(123), f.wait_timeout(Duration::milliseconds(500)).unwrap());
+}
+
+#[test]
+pub fn test_wait_timeout_elapses() {
+    let (c, f) = Future::<i32, ()>::pair();
+    let start = SteadyTime::now();
+
+    let f = f.wait_timeout(Duration::milliseconds(50)).unwrap_err();
+
+    assert!(SteadyTime::now() - start >= Duration::milliseconds(50));
+    assert!(!c.is_err());
+
+    // The future is still usable
+    c.complete(123);
+    assert_eq!(Ok(123), f.await());
+}
+
+#[test]
+pub fn test_wait_timeout_then_drop_cancels() {
+    let (c, f) = Future::<i32, ()>::pair();
+
+    let f = f.wait_timeout(Duration::milliseconds(10)).unwrap_err();
+    drop(f);
+
+    assert!(c.is_err());
+}
+
+#[test]
+pub fn test_wait_timeout_failed_future() {
+    let f = Future::<i32, &'static str>::error("nope");
+    assert_eq!(Err(AsyncError::Failed("nope")), f.wait_timeout(Duration::milliseconds(10)).unwrap());
+}
+
+#[test]
+pub fn test_future_unwrap() {
+    let (c, f) = Future::<i32, ()>::pair();
+
+    spawn(move || {
+        sleep_ms(10);
+        c.complete(123);
+    });
+
+    assert_eq!(123, f.unwrap());
+}
+
+#[test]
+#[should_panic]
+pub fn test_future_unwrap_error() {
+    Future::<i32, &'static str>::error("nope").unwrap();
+}
+
+#[test]
+pub fn test_future_unwrap_err() {
+    assert_eq!("nope", Future::<i32, &'static str>::error("nope").unwrap_err());
+}
+
+#[test]
+#[should_panic]
+pub fn test_future_unwrap_err_value() {
+    Future::<i32, ()>::of(1).unwrap_err();
+}
+
+#[test]
+#[should_panic]
+pub fn test_future_unwrap_err_aborted() {
+    let (c, f) = Future::<i32, ()>::pair();
+    drop(c);
+
+    f.unwrap_err();
+}
@@ -26,5 +26,94 @@ pub fn test_future_cancel_after_complete() {
     assert_eq!(123, rx.recv().unwrap());
 }
 
+#[test]
+pub fn test_complete_is_cancelled() {
+    let (complete, future) = Future::<i32, ()>::pair();
+    assert!(!complete.is_cancelled());
+
+    drop(future);
+    assert!(complete.is_cancelled());
+}
+
+#[test]
+pub fn test_complete_on_cancel() {
+    let (complete, future) = Future::<i32, ()>::pair();
+    let (tx, rx) = channel();
+
+    let complete = complete.on_cancel(move || tx.send("canceled").unwrap());
+    assert!(!complete.is_cancelled());
+
+    drop(future);
+
+    assert_eq!("canceled", rx.recv().unwrap());
+    assert!(complete.is_cancelled());
+}
+
+#[test]
+pub fn test_complete_on_cancel_not_invoked_on_completion() {
+    let (complete, future) = Future::<i32, ()>::pair();
+    let complete = complete.on_cancel(|| panic!("nope"));
+
+    // Not ready until the consumer registers interest
+    assert!(!complete.is_ready());
+
+    let (tx, rx) = channel();
+    future.receive(move |res| tx.send(res).unwrap());
+
+    assert!(complete.is_ready());
+    complete.complete(123);
+
+    assert_eq!(Ok(123), rx.recv().unwrap());
+}
+
+#[test]
+pub fn test_complete_on_cancel_after_interest() {
+    let (complete, future) = Future::<i32, ()>::pair();
+    let (tx, rx) = channel();
+
+    let complete = complete.on_cancel(move || tx.send("canceled").unwrap());
+
+    // Register interest, then lose it
+    let cancel = future.ready(|_| panic!("nope"));
+    assert!(complete.is_ready());
+
+    drop(cancel.cancel().expect("callback not canceled"));
+    assert!(rx.try_recv().is_err());
+
+    drop(complete);
+    assert_eq!("canceled", rx.recv().unwrap());
+}
+
+#[test]
+pub fn test_complete_on_cancel_after_interest_completed() {
+    let (complete, future) = Future::<i32, ()>::pair();
+    let (tx, rx) = channel();
+
+    let complete = complete.on_cancel(move || tx.send("canceled").unwrap());
+
+    let cancel = future.ready(|_| panic!("nope"));
+    drop(cancel.cancel().expect("callback not canceled"));
+
+    complete.complete(123);
+    assert_eq!("canceled", rx.recv().unwrap());
+}
+
 // TODO:
 // - Test blocking & cancel
+
+#[test]
+pub fn test_complete_send() {
+    let (c, f) = Future::<i32, ()>::pair();
+
+    c.send(123);
+    assert_eq!(Ok(123), f.await());
+}
+
+#[test]
+#[should_panic]
+pub fn test_complete_send_canceled() {
+    let (c, f) = Future::<i32, ()>::pair();
+
+    drop(f);
+    c.send(123);
+}
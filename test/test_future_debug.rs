@@ -0,0 +1,26 @@
+use eventual::*;
+
+#[test]
+pub fn test_future_debug_pending() {
+    let (_c, f) = Future::<i32, ()>::pair();
+    assert_eq!("Future(Pending)", format!("{:?}", f));
+}
+
+#[test]
+pub fn test_future_debug_complete() {
+    let (c, f) = Future::<i32, ()>::pair();
+    c.complete(1);
+
+    assert_eq!("Future(Complete)", format!("{:?}", f));
+}
+
+#[test]
+pub fn test_future_debug_error() {
+    let f = Future::<i32, &'static str>::error("nope");
+    assert_eq!("Future(Error)", format!("{:?}", f));
+
+    let (c, f) = Future::<i32, ()>::pair();
+    drop(c);
+
+    assert_eq!("Future(Error)", format!("{:?}", f));
+}
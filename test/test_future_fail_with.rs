@@ -0,0 +1,37 @@
+use eventual::*;
+use std::{fmt, io};
+
+#[test]
+pub fn test_fail_with_boxed_error() {
+    let (c, f) = Future::<(), BoxedError>::pair();
+    let err: BoxedError = Box::new(io::Error::new(io::ErrorKind::Other, "boom"));
+
+    c.fail_with(err);
+
+    let err = f.await().unwrap_err().unwrap();
+    assert_eq!("boom", err.to_string());
+}
+
+#[test]
+pub fn test_fail_with_heterogeneous_errors() {
+    let (c1, f1) = Future::<(), BoxedError>::pair();
+    let (c2, f2) = Future::<(), BoxedError>::pair();
+    let (c3, f3) = Future::<(), BoxedError>::pair();
+
+    c1.fail_with(io::Error::new(io::ErrorKind::Other, "io"));
+    c2.fail_with(fmt::Error);
+    c3.fail_with("message");
+
+    assert_eq!("io", f1.await().unwrap_err().unwrap().to_string());
+    assert!(f2.await().unwrap_err().unwrap().is::<fmt::Error>());
+    assert_eq!("message", f3.await().unwrap_err().unwrap().to_string());
+}
+
+#[test]
+pub fn test_boxed_error_through_combinators() {
+    let f = Future::<i32, io::Error>::error(io::Error::new(io::ErrorKind::Other, "io"))
+        .map_err(|e| -> BoxedError { Box::new(e) })
+        .and_then(|v| Ok(v + 1));
+
+    assert_eq!("io", f.await().unwrap_err().unwrap().to_string());
+}
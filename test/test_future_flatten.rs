@@ -0,0 +1,34 @@
+use eventual::*;
+
+#[test]
+pub fn test_flattening_completed_futures() {
+    let f = Future::<_, ()>::of(Future::of(123));
+    assert_eq!(Ok(123), f.flatten().await());
+}
+
+#[test]
+pub fn test_flattening_futures_async() {
+    let (c1, f1) = Future::<Future<i32, ()>, ()>::pair();
+    let (c2, f2) = Future::<i32, ()>::pair();
+
+    let f = f1.flatten();
+    assert!(!f.is_ready());
+
+    c1.complete(f2);
+    assert!(!f.is_ready());
+
+    c2.complete(123);
+    assert_eq!(Ok(123), f.await());
+}
+
+#[test]
+pub fn test_flattening_failed_outer_future() {
+    let f = Future::<Future<i32, &'static str>, &'static str>::error("outer");
+    assert_eq!(Err(AsyncError::Failed("outer")), f.flatten().await());
+}
+
+#[test]
+pub fn test_flattening_failed_inner_future() {
+    let f = Future::<_, &'static str>::of(Future::<i32, _>::error("inner"));
+    assert_eq!(Err(AsyncError::Failed("inner")), f.flatten().await());
+}
@@ -0,0 +1,37 @@
+use eventual::*;
+
+#[test]
+pub fn test_future_flatten_result_ok() {
+    let f = Future::<Result<i32, &'static str>, &'static str>::of(Ok(123));
+    assert_eq!(Ok(123), f.flatten_result().await());
+}
+
+#[test]
+pub fn test_future_flatten_result_inner_err() {
+    let f = Future::<Result<i32, &'static str>, &'static str>::of(Err("inner"));
+    assert_eq!(Err(AsyncError::Failed("inner")), f.flatten_result().await());
+}
+
+#[test]
+pub fn test_future_flatten_result_outer_err() {
+    let f = Future::<Result<i32, &'static str>, &'static str>::error("outer");
+    assert_eq!(Err(AsyncError::Failed("outer")), f.flatten_result().await());
+}
+
+#[test]
+pub fn test_future_flatten_result_async() {
+    let (c, f) = Future::<Result<i32, &'static str>, &'static str>::pair();
+    let res = f.flatten_result();
+
+    c.complete(Err("inner"));
+    assert_eq!(Err(AsyncError::Failed("inner")), res.await());
+}
+
+#[test]
+pub fn test_future_flatten_result_aborted() {
+    let (c, f) = Future::<Result<i32, &'static str>, &'static str>::pair();
+    let res = f.flatten_result();
+
+    drop(c);
+    assert_eq!(Err(AsyncError::Aborted), res.await());
+}
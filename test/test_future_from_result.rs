@@ -0,0 +1,17 @@
+use eventual::*;
+
+#[test]
+pub fn test_future_from_result_ok() {
+    let f = Future::<i32, &'static str>::from_result(Ok(123));
+
+    assert!(f.is_ready());
+    assert_eq!(Ok(123), f.await());
+}
+
+#[test]
+pub fn test_future_from_result_err() {
+    let f = Future::<i32, &'static str>::from_result(Err("nope"));
+
+    assert!(f.is_err());
+    assert_eq!(Err(AsyncError::Failed("nope")), f.await());
+}
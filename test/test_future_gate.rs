@@ -0,0 +1,45 @@
+use eventual::*;
+use super::{spawn, sleep_ms};
+
+#[test]
+pub fn test_gate_waits_for_guard() {
+    let (open, guard) = Future::<(), ()>::pair();
+    let gated = Future::of(123).gate(guard);
+
+    spawn(move || {
+        sleep_ms(50);
+        open.complete(());
+    });
+
+    assert_eq!(Ok(123), gated.await());
+}
+
+#[test]
+pub fn test_gate_guard_completes_first() {
+    let (c, f) = Future::<i32, ()>::pair();
+    let gated = f.gate(Future::of("ready"));
+
+    spawn(move || {
+        sleep_ms(50);
+        c.complete(123);
+    });
+
+    assert_eq!(Ok(123), gated.await());
+}
+
+#[test]
+pub fn test_gate_guard_fails() {
+    let (c, f) = Future::<i32, &'static str>::pair();
+    let gated = f.gate(Future::<(), &'static str>::error("closed"));
+
+    assert_eq!(Err(AsyncError::failed("closed")), gated.await());
+
+    // The gated future is dropped
+    assert!(c.is_err());
+}
+
+#[test]
+pub fn test_gate_value_fails() {
+    let gated = Future::<i32, &'static str>::error("nope").gate(Future::of(()));
+    assert_eq!(Err(AsyncError::failed("nope")), gated.await());
+}
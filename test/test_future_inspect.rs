@@ -0,0 +1,102 @@
+use eventual::*;
+use std::sync::mpsc::channel;
+
+#[test]
+pub fn test_inspecting_completed_future() {
+    let (tx, rx) = channel();
+
+    let res = Future::<i32, ()>::of(123)
+        .inspect(move |v| tx.send(*v).unwrap())
+        .await();
+
+    assert_eq!(Ok(123), res);
+    assert_eq!(123, rx.recv().unwrap());
+}
+
+#[test]
+pub fn test_inspecting_future_async() {
+    let (c, f) = Future::<String, ()>::pair();
+    let (tx, rx) = channel();
+
+    let f = f.inspect(move |v| tx.send(v.len()).unwrap());
+
+    assert!(rx.try_recv().is_err());
+    c.complete("hello".to_string());
+
+    assert_eq!(Ok("hello".to_string()), f.await());
+    assert_eq!(5, rx.recv().unwrap());
+}
+
+#[test]
+pub fn test_inspecting_failed_future() {
+    let (c, f) = Future::<i32, &'static str>::pair();
+    let (tx, rx) = channel();
+
+    let f = f.inspect(move |v| tx.send(*v).unwrap());
+    c.fail("nope");
+
+    assert_eq!(Err(AsyncError::Failed("nope")), f.await());
+    assert!(rx.recv().is_err());
+}
+
+#[test]
+pub fn test_tap_err_on_failure() {
+    let (c, f) = Future::<i32, &'static str>::pair();
+    let (tx, rx) = channel();
+
+    let f = f.tap_err(move |e| tx.send(*e).unwrap());
+
+    c.fail("nope");
+
+    assert_eq!(Err(AsyncError::failed("nope")), f.await());
+    assert_eq!("nope", rx.recv().unwrap());
+}
+
+#[test]
+pub fn test_tap_err_on_success_and_abort() {
+    let (tx, rx) = channel::<&'static str>();
+    let tx2 = tx.clone();
+
+    let res = Future::<i32, &'static str>::of(1)
+        .tap_err(move |e| tx.send(*e).unwrap())
+        .await();
+
+    assert_eq!(Ok(1), res);
+
+    let (c, f) = Future::<i32, &'static str>::pair();
+    drop(c);
+
+    let res = f.tap_err(move |e| tx2.send(*e).unwrap()).await();
+
+    assert_eq!(Err(AsyncError::aborted()), res);
+    assert!(rx.recv().is_err());
+}
+
+#[test]
+pub fn test_tap_err_or_abort() {
+    let (tx, rx) = channel::<&'static str>();
+    let tx2 = tx.clone();
+    let tx3 = tx.clone();
+
+    let res = Future::<i32, &'static str>::of(1)
+        .tap_err_or_abort("aborted", move |e| tx.send(*e).unwrap())
+        .await();
+
+    assert_eq!(Ok(1), res);
+
+    let res = Future::<i32, &'static str>::error("nope")
+        .tap_err_or_abort("aborted", move |e| tx2.send(*e).unwrap())
+        .await();
+
+    assert_eq!(Err(AsyncError::failed("nope")), res);
+    assert_eq!("nope", rx.recv().unwrap());
+
+    let (c, f) = Future::<i32, &'static str>::pair();
+    drop(c);
+
+    let res = f.tap_err_or_abort("aborted", move |e| tx3.send(*e).unwrap()).await();
+
+    assert_eq!(Err(AsyncError::aborted()), res);
+    assert_eq!("aborted", rx.recv().unwrap());
+    assert!(rx.recv().is_err());
+}
@@ -0,0 +1,69 @@
+use eventual::*;
+use std::sync::mpsc::channel;
+
+#[test]
+pub fn test_future_map_completed() {
+    let f = Future::<i32, ()>::of(123).map(|v| v + 1);
+
+    // The function is applied eagerly
+    assert!(f.is_ready());
+    assert_eq!(124, f.await().unwrap());
+}
+
+#[test]
+pub fn test_future_map_async() {
+    let (c, f) = Future::<i32, ()>::pair();
+    let (tx, rx) = channel();
+
+    f.map(|v| v * 2).receive(move |res| {
+        tx.send(res.unwrap()).unwrap();
+    });
+
+    assert!(rx.try_recv().is_err());
+    c.complete(21);
+
+    assert_eq!(42, rx.recv().unwrap());
+}
+
+#[test]
+pub fn test_future_map_failed() {
+    let f = Future::<i32, &'static str>::error("nope")
+        .map(|_| -> i32 { unreachable!() });
+
+    assert_eq!(Err(AsyncError::Failed("nope")), f.await());
+
+    let (c, f) = Future::<i32, &'static str>::pair();
+    let f = f.map(|_| -> i32 { unreachable!() });
+
+    c.fail("nope");
+    assert_eq!(Err(AsyncError::Failed("nope")), f.await());
+}
+
+#[test]
+pub fn test_future_map_aborted() {
+    let (c, f) = Future::<i32, ()>::pair();
+    let f = f.map(|_| -> i32 { unreachable!() });
+
+    drop(c);
+    assert_eq!(Err(AsyncError::Aborted), f.await());
+}
+
+#[test]
+pub fn test_async_map_result() {
+    assert_eq!(Ok(2), Async::map(Ok::<i32, ()>(1), |v| v + 1).await());
+}
+
+#[test]
+pub fn test_future_value_into() {
+    let (c, f) = Future::<&'static str, ()>::pair();
+    let f: Future<String, ()> = f.value_into();
+
+    c.complete("hello");
+    assert_eq!(Ok("hello".to_string()), f.await());
+}
+
+#[test]
+pub fn test_future_value_into_error() {
+    let f: Future<u64, &'static str> = Future::<u32, &'static str>::error("nope").value_into();
+    assert_eq!(Err(AsyncError::failed("nope")), f.await());
+}
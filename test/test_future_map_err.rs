@@ -59,3 +59,17 @@ pub fn test_future_map_err_abort() {
         _ => panic!("unexpected value"),
     }
 }
+
+#[test]
+pub fn test_async_map_err_generic() {
+    fn describe<A: Async<Error=i32>>(async: A) -> Future<A::Value, String> {
+        async.map_err(|e| format!("error {}", e))
+    }
+
+    assert_eq!(Ok(1), describe(Ok::<i32, i32>(1)).await());
+
+    match describe(Err::<(), i32>(123)).await() {
+        Err(AsyncError::Failed(e)) => assert_eq!("error 123", e),
+        _ => panic!("unexpected value"),
+    }
+}
@@ -0,0 +1,26 @@
+use eventual::*;
+use std::sync::mpsc::channel;
+use time::Duration;
+
+#[test]
+pub fn test_future_never_is_not_ready() {
+    let f = Future::<i32, ()>::never();
+
+    assert!(!f.is_ready());
+    assert!(f.wait_timeout(Duration::milliseconds(20)).is_err());
+}
+
+#[test]
+pub fn test_future_never_cancel() {
+    let (tx, rx) = channel();
+    let receipt = Future::<i32, ()>::never().ready(move |f| tx.send(f.is_ready()).unwrap());
+
+    assert!(receipt.cancel().is_some());
+    assert!(rx.try_recv().is_err());
+}
+
+#[test]
+pub fn test_future_never_in_select() {
+    let (i, _) = select((Future::<i32, ()>::never(), Future::of(1))).await().unwrap();
+    assert_eq!(1, i);
+}
@@ -0,0 +1,94 @@
+use eventual::*;
+use std::sync::mpsc::channel;
+
+#[test]
+pub fn test_future_on_complete_success() {
+    let (tx, rx) = channel();
+    let (c, f) = Future::<i32, ()>::pair();
+
+    let f = f.on_complete(move |res| tx.send(res.clone()).unwrap());
+
+    c.complete(123);
+
+    assert_eq!(Ok(123), f.await());
+    assert_eq!(Ok(Ok(123)), rx.recv());
+}
+
+#[test]
+pub fn test_future_on_complete_failure() {
+    let (tx, rx) = channel();
+
+    let f = Future::<i32, &'static str>::error("nope")
+        .on_complete(move |res| tx.send(res.clone()).unwrap());
+
+    assert_eq!(Err(AsyncError::Failed("nope")), f.await());
+    assert_eq!(Ok(Err(AsyncError::Failed("nope"))), rx.recv());
+}
+
+#[test]
+pub fn test_future_on_complete_producer_aborts() {
+    let (tx, rx) = channel();
+    let (c, f) = Future::<i32, ()>::pair();
+
+    let f = f.on_complete(move |res| tx.send(res.clone()).unwrap());
+
+    drop(c);
+
+    assert_eq!(Err(AsyncError::Aborted), f.await());
+    assert_eq!(Ok(Err(AsyncError::Aborted)), rx.recv());
+}
+
+#[test]
+pub fn test_future_on_complete_consumer_drops() {
+    let (tx, rx) = channel();
+    let (c, f) = Future::<i32, ()>::pair();
+
+    let f = f.on_complete(move |res| tx.send(res.clone()).unwrap());
+
+    drop(f);
+
+    assert_eq!(Ok(Err(AsyncError::Aborted)), rx.recv());
+    assert!(c.is_err());
+}
+
+#[test]
+pub fn test_future_on_cancel_producer_aborts() {
+    let (tx, rx) = channel();
+    let (c, f) = Future::<i32, ()>::pair();
+
+    let f = f.on_cancel(move || tx.send("canceled").unwrap());
+    drop(c);
+
+    assert!(f.await().unwrap_err().is_aborted());
+    assert_eq!(Ok("canceled"), rx.recv());
+}
+
+#[test]
+pub fn test_future_on_cancel_consumer_drops() {
+    let (tx, rx) = channel();
+    let (c, f) = Future::<i32, ()>::pair();
+
+    drop(f.on_cancel(move || tx.send("canceled").unwrap()));
+
+    assert_eq!(Ok("canceled"), rx.recv());
+    assert!(c.is_err());
+}
+
+#[test]
+pub fn test_future_on_cancel_not_invoked_on_completion() {
+    let (tx, rx) = channel::<()>();
+
+    let ok = Future::<i32, &'static str>::of(1).on_cancel({
+        let tx = tx.clone();
+        move || tx.send(()).unwrap()
+    });
+
+    let err = Future::<i32, &'static str>::error("nope")
+        .on_cancel(move || tx.send(()).unwrap());
+
+    assert_eq!(Ok(1), ok.await());
+    assert_eq!(Err(AsyncError::Failed("nope")), err.await());
+
+    // Every sender has been dropped without the callbacks being invoked
+    assert!(rx.recv().is_err());
+}
@@ -0,0 +1,47 @@
+use eventual::*;
+use time::{Duration, SteadyTime};
+
+#[test]
+pub fn test_future_pair_with_timeout_completed_in_time() {
+    let (c, f) = Future::<i32, ()>::pair_with_timeout(Duration::seconds(10));
+
+    c.complete(123);
+    assert_eq!(Ok(123), f.await());
+}
+
+#[test]
+pub fn test_future_pair_with_timeout_failed_in_time() {
+    let (c, f) = Future::<i32, &'static str>::pair_with_timeout(Duration::seconds(10));
+
+    c.fail("nope");
+    assert_eq!(Err(AsyncError::Failed("nope")), f.await());
+}
+
+#[test]
+pub fn test_future_pair_with_timeout_elapsed() {
+    let start = SteadyTime::now();
+    let (c, f) = Future::<i32, ()>::pair_with_timeout(Duration::milliseconds(50));
+
+    assert_eq!(Err(AsyncError::Aborted), f.await());
+    assert!(SteadyTime::now() - start >= Duration::milliseconds(40));
+    assert!(c.is_cancelled());
+}
+
+#[test]
+pub fn test_future_pair_with_timeout_starts_on_creation() {
+    let (c, f) = Future::<i32, ()>::pair_with_timeout(Duration::milliseconds(50));
+
+    super::sleep_ms(100);
+
+    // The consumer registering interest late does not extend the deadline
+    assert_eq!(Err(AsyncError::Aborted), f.await());
+    assert!(c.is_cancelled());
+}
+
+#[test]
+pub fn test_future_pair_with_timeout_complete_dropped() {
+    let (c, f) = Future::<i32, ()>::pair_with_timeout(Duration::seconds(10));
+
+    drop(c);
+    assert_eq!(Err(AsyncError::Aborted), f.await());
+}
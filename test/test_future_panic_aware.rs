@@ -0,0 +1,45 @@
+use eventual::*;
+use super::spawn;
+
+#[test]
+pub fn test_panic_aware_value() {
+    let (c, f) = Future::<i32, _>::panic_aware();
+    c.complete_catching(|| 123);
+
+    assert_eq!(123, f.await().ok().unwrap());
+}
+
+#[test]
+pub fn test_panic_aware_forwards_panic() {
+    let (c, f) = Future::<i32, _>::panic_aware();
+
+    spawn(move || {
+        c.complete_catching(|| panic!("boom"));
+    });
+
+    let payload = f.await().unwrap_err().unwrap();
+    assert_eq!(Some(&"boom"), payload.downcast_ref::<&'static str>());
+}
+
+#[test]
+pub fn test_panic_aware_in_ready_callback() {
+    let (c, f) = Future::<i32, _>::panic_aware();
+    let (src_tx, src) = Future::<i32, ()>::pair();
+
+    src.receive(move |res| {
+        c.complete_catching(move || {
+            let v = res.unwrap();
+
+            if v < 0 {
+                panic!("negative value: {}", v);
+            }
+
+            v
+        });
+    });
+
+    src_tx.complete(-1);
+
+    let payload = f.await().unwrap_err().unwrap();
+    assert_eq!(Some(&"negative value: -1".to_string()), payload.downcast_ref::<String>());
+}
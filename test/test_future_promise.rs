@@ -0,0 +1,17 @@
+use eventual::*;
+
+#[test]
+pub fn test_promise_complete() {
+    let (p, f): (Promise<i32, ()>, Future<i32, ()>) = promise();
+
+    p.complete(123);
+    assert_eq!(Ok(123), f.await());
+}
+
+#[test]
+pub fn test_promise_fail() {
+    let (p, f) = promise::<i32, &'static str>();
+
+    p.fail("nope");
+    assert_eq!(Err(AsyncError::Failed("nope")), f.await());
+}
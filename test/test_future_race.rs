@@ -0,0 +1,72 @@
+use eventual::*;
+
+#[test]
+pub fn test_future_race_first_wins() {
+    let (c1, f1) = Future::<i32, ()>::pair();
+    let (c2, f2) = Future::<i32, ()>::pair();
+
+    let res = f1.race(f2);
+
+    c2.complete(2);
+    assert_eq!(Ok(2), res.await());
+    assert!(c1.is_err());
+}
+
+#[test]
+pub fn test_future_race_original_wins() {
+    let (c1, f1) = Future::<i32, ()>::pair();
+    let (c2, f2) = Future::<i32, ()>::pair();
+
+    let res = f1.race(f2);
+
+    c1.complete(1);
+    assert_eq!(Ok(1), res.await());
+    assert!(c2.is_err());
+}
+
+#[test]
+pub fn test_future_race_both_ready() {
+    let res = Future::<i32, ()>::of(1).race(Future::of(2));
+    assert_eq!(Ok(1), res.await());
+}
+
+#[test]
+pub fn test_future_race_first_fails() {
+    let (c, f) = Future::<i32, &'static str>::pair();
+    let res = Future::error("nope").race(f);
+
+    assert_eq!(Err(AsyncError::Failed("nope")), res.await());
+    assert!(c.is_err());
+}
+
+#[test]
+pub fn test_future_either_left() {
+    let (c1, f1) = Future::<i32, ()>::pair();
+    let (c2, f2) = Future::<&'static str, ()>::pair();
+
+    let res = f1.either(f2);
+
+    c1.complete(1);
+    assert_eq!(Ok(Either::Left(1)), res.await());
+    assert!(c2.is_err());
+}
+
+#[test]
+pub fn test_future_either_right() {
+    let (c1, f1) = Future::<i32, ()>::pair();
+    let (c2, f2) = Future::<&'static str, ()>::pair();
+
+    let res = f1.either(f2);
+
+    c2.complete("two");
+    assert_eq!(Ok(Either::Right("two")), res.await());
+    assert!(c1.is_err());
+}
+
+#[test]
+pub fn test_future_either_fail() {
+    let (_c1, f1) = Future::<i32, &'static str>::pair();
+    let f2 = Future::<bool, &'static str>::error("nope");
+
+    assert_eq!(Err(AsyncError::failed("nope")), f1.either(f2).await());
+}
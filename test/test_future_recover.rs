@@ -0,0 +1,28 @@
+use eventual::*;
+
+#[test]
+pub fn test_future_recover_success() {
+    let (c, f) = Future::<i32, &'static str>::pair();
+    let res = f.recover(|_| panic!("nope"));
+
+    c.complete(123);
+    assert_eq!(Ok(123), res.await());
+}
+
+#[test]
+pub fn test_future_recover_failure() {
+    let (c, f) = Future::<i32, &'static str>::pair();
+    let res = f.recover(|e| e.len() as i32);
+
+    c.fail("nope");
+    assert_eq!(Ok(4), res.await());
+}
+
+#[test]
+pub fn test_future_recover_aborted() {
+    let (c, f) = Future::<i32, &'static str>::pair();
+    let res = f.recover(|_| panic!("nope"));
+
+    drop(c);
+    assert_eq!(Err(AsyncError::Aborted), res.await());
+}
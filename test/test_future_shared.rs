@@ -0,0 +1,99 @@
+use eventual::*;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::channel;
+use std::thread;
+
+#[test]
+pub fn test_shared_future_multiple_consumers() {
+    let (c, f) = Future::<i32, ()>::pair();
+    let shared = f.shared();
+
+    let a = shared.clone().map(|v| v + 1);
+    let b = shared.clone().map(|v| v * 2);
+
+    c.complete(10);
+
+    assert_eq!(Ok(11), a.await());
+    assert_eq!(Ok(20), b.await());
+    assert_eq!(Ok(10), shared.await());
+}
+
+#[test]
+pub fn test_shared_future_computed_once() {
+    let count = Arc::new(AtomicUsize::new(0));
+    let c = count.clone();
+
+    let shared = Future::<usize, ()>::spawn(move || c.fetch_add(1, Ordering::Relaxed)).shared();
+
+    let handles: Vec<_> = (0..4).map(|_| {
+        let shared = shared.clone();
+        thread::spawn(move || shared.await().unwrap())
+    }).collect();
+
+    for h in handles {
+        assert_eq!(0, h.join().unwrap());
+    }
+
+    assert_eq!(1, count.load(Ordering::Relaxed));
+}
+
+#[test]
+pub fn test_shared_future_lazy() {
+    let (c, f) = Future::<i32, ()>::pair();
+    let (tx, rx) = channel();
+
+    c.receive(move |res| {
+        if let Ok(c) = res {
+            tx.send("interest").unwrap();
+            c.complete(1);
+        }
+    });
+
+    let shared = f.shared();
+    let other = shared.clone();
+
+    assert!(rx.try_recv().is_err());
+    assert_eq!(Ok(1), other.await());
+    assert_eq!("interest", rx.recv().unwrap());
+    assert_eq!(Ok(1), shared.await());
+}
+
+#[test]
+pub fn test_shared_future_error() {
+    let (c, f) = Future::<i32, &'static str>::pair();
+    let shared = f.shared();
+    let other = shared.clone();
+
+    c.fail("nope");
+
+    assert_eq!(Err(AsyncError::Failed("nope")), shared.await());
+    assert_eq!(Err(AsyncError::Failed("nope")), other.await());
+}
+
+#[test]
+pub fn test_shared_future_dropped_cancels_original() {
+    let (c, f) = Future::<i32, ()>::pair();
+    let shared = f.shared();
+    let other = shared.clone();
+
+    drop(shared);
+    assert!(!c.is_err());
+
+    drop(other);
+    assert!(c.is_err());
+}
+
+#[test]
+pub fn test_shared_future_in_select() {
+    let (c1, f1) = Future::<i32, ()>::pair();
+    let (_c2, f2) = Future::<i32, ()>::pair();
+    let shared = f2.shared();
+
+    let sel = select((f1, shared.clone()));
+    c1.complete(1);
+
+    let (i, (_, loser)) = sel.await().unwrap();
+    assert_eq!(0, i);
+    assert!(!loser.is_ready());
+}
@@ -0,0 +1,84 @@
+use eventual::*;
+use std::sync::mpsc::channel;
+
+#[test]
+pub fn test_then_on_success() {
+    let (c, f) = Future::<i32, &'static str>::pair();
+
+    let f = f.then(|res| {
+        assert_eq!(Ok(123), res);
+        Ok::<&'static str, ()>("success")
+    });
+
+    c.complete(123);
+    assert_eq!(Ok("success"), f.await());
+}
+
+#[test]
+pub fn test_then_on_failure() {
+    let (c, f) = Future::<i32, &'static str>::pair();
+
+    let f = f.then(|res| {
+        assert_eq!(Err(AsyncError::Failed("nope")), res);
+        Err::<i32, String>("recovered".to_string())
+    });
+
+    c.fail("nope");
+    assert_eq!(Err(AsyncError::Failed("recovered".to_string())), f.await());
+}
+
+#[test]
+pub fn test_then_on_abort() {
+    let (c, f) = Future::<i32, ()>::pair();
+    let (tx, rx) = channel();
+
+    let f = f.then(move |res| {
+        tx.send(res.unwrap_err().is_aborted()).unwrap();
+        Ok::<i32, ()>(1)
+    });
+
+    drop(c);
+
+    assert_eq!(Ok(1), f.await());
+    assert!(rx.recv().unwrap());
+}
+
+#[test]
+pub fn test_then_returning_future() {
+    let (c1, f1) = Future::<i32, ()>::pair();
+    let (c2, f2) = Future::<i32, ()>::pair();
+
+    let f = f1.then(move |res| f2.map(move |v| res.unwrap() + v));
+
+    c1.complete(1);
+    assert!(!f.is_ready());
+
+    c2.complete(2);
+    assert_eq!(Ok(3), f.await());
+}
+
+#[test]
+pub fn test_then_spawn_runs_on_another_thread() {
+    use std::thread;
+
+    let (c, f) = Future::<i32, &'static str>::pair();
+    let caller = thread::current().id();
+
+    let f = f.then_spawn(move |res| {
+        assert_eq!(Ok(123), res);
+        Ok::<bool, ()>(thread::current().id() != caller)
+    });
+
+    c.complete(123);
+    assert_eq!(Ok(true), f.await());
+}
+
+#[test]
+pub fn test_then_spawn_on_failure() {
+    let f = Future::<i32, &'static str>::error("nope").then_spawn(|res| {
+        assert_eq!(Err(AsyncError::failed("nope")), res);
+        Future::<i32, ()>::error(())
+    });
+
+    assert_eq!(Err(AsyncError::failed(())), f.await());
+}
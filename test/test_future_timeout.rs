@@ -0,0 +1,70 @@
+use eventual::*;
+use std::sync::mpsc::channel;
+use time::Duration;
+use super::{spawn, sleep_ms};
+
+#[test]
+pub fn test_future_timeout_completes_in_time() {
+    let (c, f) = Future::<&'static str, TimedOut>::pair();
+
+    spawn(move || {
+        sleep_ms(20);
+        c.complete("zomg");
+    });
+
+    let res = f.timeout(Duration::milliseconds(500)).await();
+    assert_eq!(Ok("zomg"), res);
+}
+
+#[test]
+pub fn test_future_timeout_already_completed() {
+    let f = Future::<i32, TimedOut>::of(123);
+
+    let res = f.timeout(Duration::milliseconds(500)).await();
+    assert_eq!(Ok(123), res);
+}
+
+#[test]
+pub fn test_future_timeout_failed_in_time() {
+    #[derive(Debug, PartialEq)]
+    enum Error {
+        TimedOut,
+        Other,
+    }
+
+    impl From<TimedOut> for Error {
+        fn from(_: TimedOut) -> Error {
+            Error::TimedOut
+        }
+    }
+
+    let f = Future::<(), Error>::error(Error::Other);
+
+    let res = f.timeout(Duration::milliseconds(500)).await();
+    assert_eq!(Err(AsyncError::Failed(Error::Other)), res);
+}
+
+#[test]
+pub fn test_future_timeout_elapses() {
+    let (c, f) = Future::<i32, TimedOut>::pair();
+    let (tx, rx) = channel();
+
+    f.timeout(Duration::milliseconds(50)).receive(move |res| {
+        tx.send(res).unwrap();
+    });
+
+    assert_eq!(Err(AsyncError::Failed(TimedOut)), rx.recv().unwrap());
+
+    // The timed out computation has been canceled
+    assert!(c.is_err());
+}
+
+#[test]
+pub fn test_stream_timeout_elapses() {
+    let (_tx, rx) = Stream::<i32, TimedOut>::pair();
+
+    match rx.timeout(Duration::milliseconds(50)).await() {
+        Err(AsyncError::Failed(TimedOut)) => {}
+        _ => panic!("unexpected value"),
+    }
+}
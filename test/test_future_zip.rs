@@ -0,0 +1,65 @@
+use eventual::*;
+use std::sync::mpsc::channel;
+
+#[test]
+pub fn test_future_zip_async() {
+    let (c1, f1) = Future::<i32, ()>::pair();
+    let (c2, f2) = Future::<&'static str, ()>::pair();
+    let (tx, rx) = channel();
+
+    f1.zip(f2).receive(move |res| {
+        tx.send(res.unwrap()).unwrap();
+    });
+
+    assert!(rx.try_recv().is_err());
+    c2.complete("two");
+
+    assert!(rx.try_recv().is_err());
+    c1.complete(1);
+
+    assert_eq!((1, "two"), rx.recv().unwrap());
+}
+
+#[test]
+pub fn test_future_zip_runs_concurrently() {
+    let (c1, f1) = Future::<i32, ()>::pair();
+    let (c2, f2) = Future::<i32, ()>::pair();
+
+    let zipped = f1.zip(f2);
+
+    assert!(!c1.is_ready());
+    assert!(!c2.is_ready());
+
+    zipped.receive(drop);
+
+    // Interest is registered on both futures at the same time
+    assert!(c1.is_ready());
+    assert!(c2.is_ready());
+}
+
+#[test]
+pub fn test_future_zip_first_error_cancels_other() {
+    let (c1, f1) = Future::<i32, &'static str>::pair();
+    let (c2, f2) = Future::<i32, &'static str>::pair();
+    let (tx, rx) = channel();
+
+    f1.zip(f2).receive(move |res| {
+        tx.send(res).unwrap();
+    });
+
+    c2.fail("nope");
+
+    assert_eq!(Err(AsyncError::Failed("nope")), rx.recv().unwrap());
+    assert!(c1.is_err());
+}
+
+#[test]
+pub fn test_future_zip_second_error() {
+    let (c1, f1) = Future::<i32, &'static str>::pair();
+    let (c2, f2) = Future::<i32, &'static str>::pair();
+
+    c1.complete(1);
+    c2.fail("nope");
+
+    assert_eq!(Err(AsyncError::Failed("nope")), f1.zip(f2).await());
+}
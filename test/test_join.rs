@@ -123,3 +123,153 @@ pub fn test_empty_join() {
     let v: Vec<Future<i32, ()>> = vec![];
     assert_eq!(join(v).await().ok(), Some(vec![]));
 }
+
+#[test]
+pub fn test_join_all_preserves_order() {
+    let (c1, f1) = Future::<i32, ()>::pair();
+    let (c2, f2) = Future::<i32, ()>::pair();
+    let (c3, f3) = Future::<i32, ()>::pair();
+
+    spawn(move || {
+        c3.complete(3);
+        sleep_ms(25);
+        c1.complete(1);
+        sleep_ms(25);
+        c2.complete(2);
+    });
+
+    let vals = join_all(vec![f1, f2, f3]).await().unwrap();
+    assert_eq!([1, 2, 3], &vals[..]);
+}
+
+#[test]
+pub fn test_join_all_fails_fast_and_cancels_remaining() {
+    let (c1, f1) = Future::<i32, &'static str>::pair();
+    let (c2, f2) = Future::<i32, &'static str>::pair();
+    let (c3, f3) = Future::<i32, &'static str>::pair();
+
+    let join = join_all(vec![f1, f2, f3]);
+    let (tx, rx) = channel();
+
+    join.receive(move |res| tx.send(res).unwrap());
+
+    c1.complete(1);
+    c2.fail("nope");
+
+    assert_eq!(Err(AsyncError::Failed("nope")), rx.recv().unwrap());
+    assert!(c3.is_err());
+}
+
+#[test]
+pub fn test_join_all_already_failed() {
+    let (c1, f1) = Future::<i32, &'static str>::pair();
+    let (c2, f2) = Future::<i32, &'static str>::pair();
+
+    c1.fail("nope");
+
+    let res = join_all(vec![f1, f2]).await();
+
+    assert_eq!(Err(AsyncError::Failed("nope")), res);
+    assert!(c2.is_err());
+}
+
+#[test]
+pub fn test_joining_four_futures_of_different_types() {
+    let (c1, f1) = Future::<i32, ()>::pair();
+    let (c2, f2) = Future::<&'static str, ()>::pair();
+
+    spawn(move || {
+        c2.complete("two");
+        sleep_ms(25);
+        c1.complete(1);
+    });
+
+    let vals = join((f1, f2, Future::of(3.0), Future::of(Some(4)))).await().unwrap();
+    assert_eq!((1, "two", 3.0, Some(4)), vals);
+}
+
+#[test]
+pub fn test_joining_eight_futures() {
+    let vals = join((
+        Future::<i32, ()>::of(1), Future::of(2), Future::of(3), Future::of(4),
+        Future::of(5), Future::of(6), Future::of(7), Future::of(8))).await().unwrap();
+
+    assert_eq!((1, 2, 3, 4, 5, 6, 7, 8), vals);
+}
+
+#[test]
+pub fn test_join_tuple_fails_fast_and_cancels_remaining() {
+    let (c1, f1) = Future::<i32, &'static str>::pair();
+    let (c2, f2) = Future::<&'static str, &'static str>::pair();
+    let (c3, f3) = Future::<i32, &'static str>::pair();
+    let (c4, f4) = Future::<bool, &'static str>::pair();
+
+    let (tx, rx) = channel();
+
+    join((f1, f2, f3, f4)).receive(move |res| tx.send(res.map(|_| ())).unwrap());
+
+    c1.complete(1);
+    c3.fail("nope");
+
+    assert_eq!(Err(AsyncError::Failed("nope")), rx.recv().unwrap());
+    assert!(c2.is_err());
+    assert!(c4.is_err());
+}
+
+#[test]
+pub fn test_join_with_limit_preserves_order() {
+    let pairs: Vec<_> = (0..4).map(|_| Future::<i32, ()>::pair()).collect();
+    let (completes, futures): (Vec<_>, Vec<_>) = pairs.into_iter().unzip();
+    let (tx, rx) = channel();
+
+    join_with_limit(futures, 2).receive(move |res| tx.send(res).unwrap());
+
+    let interested: Vec<bool> = completes.iter().map(|c| c.is_ready()).collect();
+    assert_eq!([true, true, false, false], &interested[..]);
+
+    let mut completes: Vec<_> = completes.into_iter().map(Some).collect();
+
+    // Completing the first one starts the next
+    completes[0].take().unwrap().complete(0);
+    assert!(completes[2].as_ref().unwrap().is_ready());
+    assert!(!completes[3].as_ref().unwrap().is_ready());
+
+    for &i in [3, 2, 1].iter() {
+        completes[i].take().unwrap().complete(i as i32);
+    }
+
+    assert_eq!(Ok(vec![0, 1, 2, 3]), rx.recv().unwrap());
+}
+
+#[test]
+pub fn test_join_with_limit_fails_fast() {
+    let (c1, f1) = Future::<i32, &'static str>::pair();
+    let (c2, f2) = Future::<i32, &'static str>::pair();
+    let (tx, rx) = channel();
+
+    join_with_limit(vec![f1, f2], 2).receive(move |res| tx.send(res).unwrap());
+
+    // Fails without waiting on the first future, which is canceled
+    c2.fail("nope");
+    assert_eq!(Err(AsyncError::Failed("nope")), rx.try_recv().unwrap());
+    assert!(c1.is_err());
+}
+
+#[test]
+pub fn test_join_with_limit_fail() {
+    let (c1, f1) = Future::<i32, &'static str>::pair();
+    let (c2, f2) = Future::<i32, &'static str>::pair();
+    let (c3, f3) = Future::<i32, &'static str>::pair();
+
+    let join = join_with_limit(vec![f1, f2, f3], 1);
+    let (tx, rx) = channel();
+
+    join.receive(move |res| tx.send(res).unwrap());
+
+    c1.fail("nope");
+
+    // The futures that were not started are canceled
+    assert_eq!(Err(AsyncError::Failed("nope")), rx.recv().unwrap());
+    assert!(c2.is_err());
+    assert!(c3.is_err());
+}
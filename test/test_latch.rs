@@ -0,0 +1,55 @@
+use eventual::*;
+use std::thread;
+
+#[test]
+pub fn test_latch_first_resolve_wins() {
+    let (latch, future) = Latch::<i32, ()>::pair();
+    assert!(!latch.is_done());
+
+    assert!(latch.resolve(1));
+    assert!(!latch.resolve(2));
+    assert!(!latch.fail(()));
+    assert!(latch.is_done());
+
+    assert_eq!(Ok(1), future.await());
+}
+
+#[test]
+pub fn test_latch_fail() {
+    let (latch, future) = Latch::<i32, &'static str>::pair();
+
+    assert!(latch.clone().fail("nope"));
+    assert!(!latch.resolve(1));
+
+    assert_eq!(Err(AsyncError::Failed("nope")), future.await());
+}
+
+#[test]
+pub fn test_latch_competing_threads() {
+    let (latch, future) = Latch::<usize, ()>::pair();
+
+    let threads: Vec<_> = (0..8).map(|i| {
+        let latch = latch.clone();
+        thread::spawn(move || latch.resolve(i))
+    }).collect();
+
+    let wins = threads.into_iter()
+        .map(|th| th.join().unwrap())
+        .filter(|won| *won)
+        .count();
+
+    assert_eq!(1, wins);
+    assert!(future.await().unwrap() < 8);
+}
+
+#[test]
+pub fn test_latch_dropped() {
+    let (latch, future) = Latch::<i32, ()>::pair();
+    let other = latch.clone();
+
+    drop(latch);
+    assert!(!future.is_ready());
+
+    drop(other);
+    assert_eq!(Err(AsyncError::Aborted), future.await());
+}
@@ -0,0 +1,78 @@
+use eventual::*;
+use super::{spawn, sleep_ms};
+
+#[test]
+pub fn test_notify_wakes_pending_wait() {
+    let (notifier, notification) = Notify::new();
+    let wait = notification.wait();
+
+    assert!(!wait.is_ready());
+
+    spawn(move || {
+        sleep_ms(50);
+        notifier.notify();
+    });
+
+    assert_eq!(Ok(()), wait.await());
+}
+
+#[test]
+pub fn test_notify_before_wait() {
+    let (notifier, notification) = Notify::new();
+
+    notifier.notify();
+    assert_eq!(Ok(()), notification.wait().await());
+}
+
+#[test]
+pub fn test_notify_collapses_notifications() {
+    let (notifier, notification) = Notify::new();
+
+    for _ in 0..3 {
+        notifier.notify();
+    }
+
+    assert_eq!(Ok(()), notification.wait().await());
+    assert!(!notification.wait().is_ready());
+}
+
+#[test]
+pub fn test_notify_wakes_waits_in_order() {
+    let (notifier, notification) = Notify::new();
+
+    let first = notification.wait();
+    let second = notification.wait();
+
+    notifier.notify();
+    assert_eq!(Ok(()), first.await());
+    assert!(!second.is_ready());
+
+    notifier.notify();
+    assert_eq!(Ok(()), second.await());
+}
+
+#[test]
+pub fn test_notify_skips_dropped_wait() {
+    let (notifier, notification) = Notify::new();
+
+    drop(notification.wait());
+    let wait = notification.wait();
+
+    notifier.notify();
+    assert_eq!(Ok(()), wait.await());
+}
+
+#[test]
+pub fn test_notify_fails_when_notifiers_dropped() {
+    let (notifier, notification) = Notify::new();
+    let other = notifier.clone();
+
+    let wait = notification.wait();
+
+    drop(notifier);
+    assert!(!wait.is_ready());
+
+    drop(other);
+    assert_eq!(Err(AsyncError::failed(())), wait.await());
+    assert_eq!(Err(AsyncError::failed(())), notification.wait().await());
+}
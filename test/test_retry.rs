@@ -0,0 +1,87 @@
+use eventual::*;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use time::{Duration, SteadyTime};
+
+#[test]
+pub fn test_retry_succeeds_first_time() {
+    let count = Arc::new(AtomicUsize::new(0));
+    let c = count.clone();
+
+    let res = retry(move || {
+        c.fetch_add(1, Ordering::Relaxed);
+        Future::<i32, ()>::of(123)
+    }, 3).await();
+
+    assert_eq!(Ok(123), res);
+    assert_eq!(1, count.load(Ordering::Relaxed));
+}
+
+#[test]
+pub fn test_retry_returns_last_error() {
+    let count = Arc::new(AtomicUsize::new(0));
+    let c = count.clone();
+
+    let res = retry(move || {
+        Future::<(), usize>::error(c.fetch_add(1, Ordering::Relaxed))
+    }, 3).await();
+
+    assert_eq!(Err(AsyncError::Failed(2)), res);
+    assert_eq!(3, count.load(Ordering::Relaxed));
+}
+
+#[test]
+pub fn test_retry_is_lazy() {
+    let count = Arc::new(AtomicUsize::new(0));
+    let c = count.clone();
+
+    let _f = retry(move || {
+        c.fetch_add(1, Ordering::Relaxed);
+        Future::<i32, ()>::of(123)
+    }, 3);
+
+    assert_eq!(0, count.load(Ordering::Relaxed));
+}
+
+#[test]
+pub fn test_retry_async_failures() {
+    let count = Arc::new(AtomicUsize::new(0));
+    let c = count.clone();
+
+    let res = retry(move || {
+        let n = c.fetch_add(1, Ordering::Relaxed);
+
+        Future::spawn(move || n).and_then(|n| {
+            if n < 2 { Err(()) } else { Ok(n) }
+        })
+    }, 5).await();
+
+    assert_eq!(Ok(2), res);
+}
+
+#[test]
+pub fn test_retry_with_backoff_waits() {
+    let start = SteadyTime::now();
+
+    let res = retry_with_backoff(|| Err::<(), ()>(()), 3, Backoff::Linear(Duration::milliseconds(50))).await();
+
+    // Waits 50ms and then 100ms
+    assert_eq!(Err(AsyncError::Failed(())), res);
+    assert!(SteadyTime::now() - start >= Duration::milliseconds(140));
+}
+
+#[test]
+pub fn test_backoff_delays() {
+    let ms = Duration::milliseconds;
+
+    assert_eq!(ms(0), Backoff::Immediate.delay(3));
+    assert_eq!(ms(30), Backoff::Linear(ms(10)).delay(3));
+    assert_eq!(ms(10), Backoff::Exponential(ms(10)).delay(1));
+    assert_eq!(ms(40), Backoff::Exponential(ms(10)).delay(3));
+}
+
+#[test]
+#[should_panic]
+pub fn test_retry_zero_attempts() {
+    let _ = retry(|| Ok::<(), ()>(()), 0);
+}
@@ -3,8 +3,9 @@ extern crate syncbox;
 use std::sync::atomic::AtomicBool;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
+use std::sync::mpsc::channel;
 use std::thread;
-use eventual::{background, defer, Future, Async};
+use eventual::{background, background_catching, defer, defer_with, Future, Async, AsyncError};
 
 // TODO figure out how to get rid of unused import error here
 use syncbox::ThreadPool;
@@ -38,3 +39,140 @@ fn test_threadpool_background() {
     flag.store(true, Ordering::Relaxed);
     assert_eq!(Ok(5), result.await());
 }
+
+#[test]
+fn test_defer_with_moves_value() {
+    let pool = ThreadPool::single_thread();
+    let caller = thread::current().id();
+
+    let result = defer_with(pool, vec![1, 2, 3], move |vals| {
+        assert!(thread::current().id() != caller);
+        vals.into_iter().fold(0, |sum, v| sum + v)
+    });
+
+    assert_eq!(Ok(6), result.await());
+}
+
+#[test]
+fn test_thread_pool_spawn() {
+    let pool = eventual::ThreadPool::new(2);
+
+    let futures: Vec<_> = (0..10).map(|i| pool.spawn(move || i * 2)).collect();
+    let vals: Vec<i32> = futures.into_iter().map(|f| f.await().unwrap()).collect();
+
+    assert_eq!(vec![0, 2, 4, 6, 8, 10, 12, 14, 16, 18], vals);
+}
+
+#[test]
+fn test_thread_pool_bounds_concurrency() {
+    use std::sync::atomic::AtomicUsize;
+
+    let pool = eventual::ThreadPool::new(2);
+    let running = Arc::new(AtomicUsize::new(0));
+    let max = Arc::new(AtomicUsize::new(0));
+
+    let futures: Vec<_> = (0..6).map(|_| {
+        let running = running.clone();
+        let max = max.clone();
+
+        pool.spawn(move || {
+            let curr = running.fetch_add(1, Ordering::SeqCst) + 1;
+
+            if curr > max.load(Ordering::SeqCst) {
+                max.store(curr, Ordering::SeqCst);
+            }
+
+            ::sleep_ms(20);
+            running.fetch_sub(1, Ordering::SeqCst);
+        })
+    }).collect();
+
+    for f in futures {
+        f.await().unwrap();
+    }
+
+    assert!(max.load(Ordering::SeqCst) <= 2);
+}
+
+#[test]
+fn test_thread_pool_as_task_runner() {
+    let pool = eventual::ThreadPool::new(1);
+    let result = background(pool, Box::new(|| 5));
+
+    assert_eq!(Ok(5), result.await());
+}
+
+#[test]
+fn test_future_spawn_with_executor() {
+    use eventual::ThreadPool;
+    use syncbox::{Run, TaskBox, Task};
+
+    let pool = ThreadPool::new(1);
+    let future = Future::spawn_with_executor(|| 42, &pool);
+    assert_eq!(Ok(42), future.await());
+
+    // Runs every task immediately on the calling thread
+    struct Inline;
+
+    impl Run<Box<TaskBox>> for Inline {
+        fn run(&self, task: Box<TaskBox>) {
+            task.run();
+        }
+    }
+
+    let future = Future::spawn_with_executor(|| 1 + 1, &Inline);
+    assert!(future.is_ready());
+    assert_eq!(Ok(2), future.await());
+}
+
+#[test]
+fn test_background_catching_value() {
+    let pool = ThreadPool::single_thread();
+    let res = background_catching(pool, || 42).await();
+
+    assert_eq!(42, res.ok().unwrap());
+}
+
+#[test]
+fn test_background_catching_panic() {
+    let pool = ThreadPool::single_thread();
+    let res = background_catching(pool.clone(), || -> i32 { panic!("boom") }).await();
+
+    match res {
+        Err(AsyncError::Failed(payload)) => {
+            assert_eq!(Some(&"boom"), payload.downcast_ref::<&'static str>());
+        }
+        _ => panic!("expected the panic to be caught"),
+    }
+
+    // The pool's thread survived the panic
+    assert_eq!(1, background_catching(pool, || 1).await().ok().unwrap());
+}
+
+#[test]
+fn test_and_then_spawn_runs_on_task_runner() {
+    let pool = ThreadPool::single_thread();
+    let (tx, rx) = channel();
+    let (complete, future) = Future::<i32, ()>::pair();
+
+    let caller = thread::current().id();
+
+    let res = future.and_then_spawn(move |v| {
+        tx.send(thread::current().id()).unwrap();
+        Ok(v + 1)
+    }, pool);
+
+    complete.complete(1);
+    assert_eq!(Ok(2), res.await());
+    assert!(rx.recv().unwrap() != caller);
+}
+
+#[test]
+fn test_and_then_spawn_failure_skips_callback() {
+    let pool = ThreadPool::single_thread();
+
+    let res = Future::<i32, &'static str>::error("nope")
+        .and_then_spawn(|_| -> Result<i32, &'static str> { panic!("should not be invoked") }, pool);
+
+    assert_eq!(Err(AsyncError::failed("nope")), res.await());
+}
@@ -76,3 +76,15 @@ pub fn test_selecting_two_completed_futures_async() {
     assert_eq!(123, f1.expect().unwrap());
     assert_eq!(234, f2.expect().unwrap());
 }
+
+#[test]
+pub fn test_selecting_already_failed_future_cancels_others() {
+    let (c1, f1) = Future::<i32, i32>::pair();
+    let (c2, f2) = Future::<i32, i32>::pair();
+
+    let sel = eventual::select((f1, f2));
+    c1.fail(123);
+
+    assert_eq!(123, sel.await().unwrap_err().unwrap());
+    assert!(c2.is_err());
+}
@@ -0,0 +1,73 @@
+use eventual::*;
+
+#[test]
+pub fn test_select_any_first_complete() {
+    let (c1, f1) = Future::<i32, ()>::pair();
+    let (c2, f2) = Future::<i32, ()>::pair();
+    let (c3, f3) = Future::<i32, ()>::pair();
+
+    let sel = select_any(vec![f1, f2, f3]);
+
+    c2.complete(2);
+
+    let (val, rest) = sel.await().unwrap();
+    assert_eq!(2, val);
+    assert_eq!(2, rest.len());
+
+    // The remaining futures are still live
+    c3.complete(3);
+    c1.complete(1);
+
+    let vals: Vec<i32> = rest.into_iter().map(|f| f.await().unwrap()).collect();
+    assert_eq!([1, 3], &vals[..]);
+}
+
+#[test]
+pub fn test_select_any_already_complete() {
+    let (_c1, f1) = Future::<i32, ()>::pair();
+    let f2 = Future::of(2);
+
+    let (val, rest) = select_any(vec![f1, f2]).await().unwrap();
+
+    assert_eq!(2, val);
+    assert_eq!(1, rest.len());
+    assert!(!rest[0].is_ready());
+}
+
+#[test]
+pub fn test_select_any_remaining_cancelable() {
+    let (c1, f1) = Future::<i32, ()>::pair();
+    let (c2, f2) = Future::<i32, ()>::pair();
+
+    let sel = select_any(vec![f1, f2]);
+    c1.complete(1);
+
+    let (val, rest) = sel.await().unwrap();
+    assert_eq!(1, val);
+
+    drop(rest);
+    assert!(c2.is_err());
+}
+
+#[test]
+pub fn test_select_any_error() {
+    let (c1, f1) = Future::<i32, &'static str>::pair();
+    let (c2, f2) = Future::<i32, &'static str>::pair();
+
+    let sel = select_any(vec![f1, f2]);
+    c1.fail("nope");
+
+    match sel.await() {
+        Err(AsyncError::Failed("nope")) => {}
+        _ => panic!("unexpected result"),
+    }
+
+    // The losing future was canceled
+    assert!(c2.is_err());
+}
+
+#[test]
+pub fn test_select_any_empty() {
+    let sel = select_any(Vec::<Future<i32, ()>>::new());
+    assert!(sel.await().unwrap_err().is_aborted());
+}
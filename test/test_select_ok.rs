@@ -0,0 +1,56 @@
+use eventual::*;
+use super::{spawn, sleep_ms};
+
+#[test]
+pub fn test_select_ok_skips_failures() {
+    let (c1, f1) = Future::<i32, &'static str>::pair();
+    let (c2, f2) = Future::<i32, &'static str>::pair();
+    let (c3, f3) = Future::<i32, &'static str>::pair();
+
+    spawn(move || {
+        c1.fail("first");
+        sleep_ms(10);
+        c3.complete(3);
+        sleep_ms(10);
+        c2.complete(2);
+    });
+
+    assert_eq!(Ok((2, 3)), select_ok(vec![f1, f2, f3]).await());
+}
+
+#[test]
+pub fn test_select_ok_all_fail() {
+    let (c1, f1) = Future::<i32, &'static str>::pair();
+    let (c2, f2) = Future::<i32, &'static str>::pair();
+
+    let sel = select_ok(vec![f1, f2]);
+
+    c2.fail("second");
+    c1.fail("first");
+
+    assert_eq!(Err(AsyncError::failed(vec!["first", "second"])), sel.await());
+}
+
+#[test]
+pub fn test_select_ok_does_not_wait_for_remaining() {
+    let (_c1, f1) = Future::<i32, ()>::pair();
+    let f2 = Future::of(2);
+
+    assert_eq!(Ok((1, 2)), select_ok(vec![f1, f2]).await());
+}
+
+#[test]
+pub fn test_select_ok_aborted() {
+    let (c1, f1) = Future::<i32, &'static str>::pair();
+    let f2 = Future::error("nope");
+
+    drop(c1);
+
+    assert_eq!(Err(AsyncError::aborted()), select_ok(vec![f1, f2]).await());
+}
+
+#[test]
+pub fn test_select_ok_empty() {
+    let futures: Vec<Future<i32, ()>> = vec![];
+    assert_eq!(Err(AsyncError::failed(vec![])), select_ok(futures).await());
+}
@@ -0,0 +1,52 @@
+use eventual::*;
+use super::nums;
+
+#[test]
+pub fn test_select_stream_alternates_ready_streams() {
+    let vals = select_stream(nums::<()>(0, 3), nums::<()>(10, 13))
+        .collect().await().unwrap();
+
+    assert_eq!(vec![0, 10, 1, 11, 2, 12], vals);
+}
+
+#[test]
+pub fn test_select_stream_continues_after_one_ends() {
+    let mut vals = select_stream(nums::<()>(0, 1), nums::<()>(10, 14))
+        .collect().await().unwrap();
+
+    vals.sort();
+    assert_eq!(vec![0, 10, 11, 12, 13], vals);
+}
+
+#[test]
+pub fn test_select_stream_yields_first_available() {
+    let (tx_a, rx_a) = Stream::<i32, ()>::pair();
+    let (tx_b, rx_b) = Stream::<i32, ()>::pair();
+
+    let vals = select_stream(rx_a, rx_b).collect();
+
+    tx_b.send(1)
+        .and_then(|tx| tx.send(2))
+        .and_then(move |tx_b| {
+            tx_a.send(3).and_then(move |_| {
+                drop(tx_b);
+                Ok(())
+            })
+        })
+        .fire();
+
+    assert_eq!(Ok(vec![1, 2, 3]), vals.await());
+}
+
+#[test]
+pub fn test_select_stream_failure() {
+    let (tx_a, rx_a) = Stream::<i32, &'static str>::pair();
+    let (tx_b, rx_b) = Stream::<i32, &'static str>::pair();
+
+    let vals = select_stream(rx_a, rx_b).collect();
+
+    tx_a.fail("nope");
+
+    assert_eq!(Err(AsyncError::Failed("nope")), vals.await());
+    assert!(tx_b.is_err());
+}
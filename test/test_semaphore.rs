@@ -0,0 +1,118 @@
+use eventual::*;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::channel;
+use super::{spawn, sleep_ms};
+
+#[test]
+pub fn test_semaphore_acquire_available() {
+    let sem = Semaphore::new(2);
+
+    let a = sem.acquire().await().unwrap();
+    let b = sem.acquire().await().unwrap();
+    assert_eq!(0, sem.available_permits());
+
+    drop(a);
+    assert_eq!(1, sem.available_permits());
+
+    drop(b);
+    assert_eq!(2, sem.available_permits());
+}
+
+#[test]
+pub fn test_semaphore_waiters_are_fifo() {
+    let sem = Semaphore::new(1);
+    let permit = sem.acquire().await().unwrap();
+    let (tx, rx) = channel();
+
+    for i in 0..3 {
+        let tx = tx.clone();
+
+        sem.acquire().receive(move |res| {
+            let permit = res.unwrap();
+            tx.send(i).unwrap();
+            drop(permit);
+        });
+    }
+
+    assert!(rx.try_recv().is_err());
+    drop(permit);
+
+    assert_eq!(vec![0, 1, 2], rx.iter().take(3).collect::<Vec<i32>>());
+    assert_eq!(1, sem.available_permits());
+}
+
+#[test]
+pub fn test_semaphore_limits_concurrency() {
+    let sem = Semaphore::new(2);
+    let active = Arc::new(AtomicUsize::new(0));
+    let max = Arc::new(AtomicUsize::new(0));
+    let (tx, rx) = channel();
+
+    for _ in 0..6 {
+        let sem = sem.clone();
+        let active = active.clone();
+        let max = max.clone();
+        let tx = tx.clone();
+
+        spawn(move || {
+            let permit = sem.acquire().await().unwrap();
+            let n = active.fetch_add(1, Ordering::SeqCst) + 1;
+
+            if n > max.load(Ordering::SeqCst) {
+                max.store(n, Ordering::SeqCst);
+            }
+
+            sleep_ms(20);
+            active.fetch_sub(1, Ordering::SeqCst);
+            drop(permit);
+            tx.send(()).unwrap();
+        });
+    }
+
+    for _ in 0..6 {
+        rx.recv().unwrap();
+    }
+
+    assert!(max.load(Ordering::SeqCst) <= 2);
+}
+
+#[test]
+pub fn test_semaphore_dropped_waiter_is_skipped() {
+    let sem = Semaphore::new(1);
+    let permit = sem.acquire().await().unwrap();
+
+    let first = sem.acquire();
+    let second = sem.acquire();
+    drop(first);
+
+    drop(permit);
+    assert!(second.await().is_ok());
+}
+
+#[test]
+pub fn test_semaphore_try_acquire() {
+    let sem = Semaphore::new(1);
+
+    let permit = sem.try_acquire().unwrap();
+    assert!(sem.try_acquire().is_none());
+
+    drop(permit);
+    assert!(sem.try_acquire().is_some());
+}
+
+#[test]
+pub fn test_semaphore_close() {
+    let sem = Semaphore::new(1);
+    let permit = sem.acquire().await().unwrap();
+    let waiting = sem.acquire();
+
+    sem.close();
+
+    assert_eq!(Err(AsyncError::failed(SemaphoreError)), waiting.await().map(|_| ()));
+    assert_eq!(Err(AsyncError::failed(SemaphoreError)), sem.acquire().await().map(|_| ()));
+
+    // Permits acquired before closing are still handed back
+    drop(permit);
+    assert_eq!(1, sem.available_permits());
+}
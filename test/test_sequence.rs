@@ -79,3 +79,178 @@ pub fn test_sequencing_failed_future() {
 
     assert_eq!("win", rx.recv().unwrap());
 }
+
+/*
+ *
+ * ===== sequence_n() =====
+ *
+ */
+
+#[test]
+pub fn test_sequence_n_limits_in_flight() {
+    let pairs: Vec<_> = (0..5).map(|_| Future::<i32, ()>::pair()).collect();
+    let (completes, futures): (Vec<_>, Vec<_>) = pairs.into_iter().unzip();
+    let (tx, rx) = channel();
+
+    eventual::sequence_n(futures, 2).receive(move |res| tx.send(res).unwrap());
+
+    let interested: Vec<bool> = completes.iter().map(|c| c.is_ready()).collect();
+    assert_eq!([true, true, false, false, false], &interested[..]);
+
+    let mut completes: Vec<_> = completes.into_iter().map(Some).collect();
+
+    // Completing one starts the next
+    completes[1].take().unwrap().complete(1);
+    assert!(completes[2].as_ref().unwrap().is_ready());
+    assert!(!completes[3].as_ref().unwrap().is_ready());
+
+    for &i in [2, 0, 4, 3].iter() {
+        completes[i].take().unwrap().complete(i as i32);
+    }
+
+    assert_eq!(Ok(vec![1, 2, 0, 4, 3]), rx.recv().unwrap());
+}
+
+#[test]
+pub fn test_sequence_n_ordered() {
+    let (c1, f1) = Future::<i32, ()>::pair();
+    let (c2, f2) = Future::<i32, ()>::pair();
+    let (c3, f3) = Future::<i32, ()>::pair();
+    let (tx, rx) = channel();
+
+    eventual::sequence_n_ordered(vec![f1, f2, f3], 3).receive(move |res| tx.send(res).unwrap());
+
+    c3.complete(3);
+    c1.complete(1);
+    c2.complete(2);
+
+    assert_eq!(Ok(vec![1, 2, 3]), rx.recv().unwrap());
+}
+
+#[test]
+pub fn test_sequence_n_completed_values() {
+    let futures: Vec<_> = (0..10).map(|i| Future::<i32, ()>::of(i)).collect();
+    let vals = eventual::sequence_n(futures, 3).await().unwrap();
+
+    assert_eq!((0..10).collect::<Vec<i32>>(), vals);
+}
+
+#[test]
+pub fn test_sequence_n_empty() {
+    let futures: Vec<Future<i32, ()>> = vec![];
+    assert_eq!(Ok(vec![]), eventual::sequence_n(futures, 3).await());
+}
+
+#[test]
+pub fn test_sequence_n_failure_drops_pending() {
+    let (c1, f1) = Future::<i32, &'static str>::pair();
+    let (c2, f2) = Future::<i32, &'static str>::pair();
+
+    let res = eventual::sequence_n(vec![f1, f2], 1);
+    let (tx, rx) = channel();
+    res.receive(move |res| tx.send(res).unwrap());
+
+    c1.fail("nope");
+
+    assert_eq!(Err(AsyncError::Failed("nope")), rx.recv().unwrap());
+    assert!(c2.is_err());
+}
+
+#[test]
+pub fn test_sequence_stream_preserves_order() {
+    let (c1, f1) = Future::<i32, ()>::pair();
+    let (c2, f2) = Future::<i32, ()>::pair();
+    let (tx, rx) = Stream::pair();
+
+    let vals = eventual::sequence_stream(rx).collect();
+
+    tx.send(f1)
+        .and_then(move |tx| tx.send(f2))
+        .fire();
+
+    // Completing out of order does not reorder the values
+    c2.complete(2);
+    c1.complete(1);
+
+    assert_eq!(Ok(vec![1, 2]), vals.await());
+}
+
+#[test]
+pub fn test_sequence_stream_requests_next_after_completion() {
+    let (c1, f1) = Future::<i32, ()>::pair();
+    let (tx, rx) = Stream::pair();
+    let (sent_tx, sent_rx) = channel();
+
+    let vals = eventual::sequence_stream(rx);
+    let (done_tx, done_rx) = channel();
+    vals.collect().receive(move |res| done_tx.send(res).unwrap());
+
+    tx.send(f1)
+        .and_then(move |tx| {
+            sent_tx.send(()).unwrap();
+            tx.send(Future::of(2))
+        })
+        .fire();
+
+    // The second future is not requested until the first one completes
+    assert!(sent_rx.try_recv().is_err());
+    c1.complete(1);
+
+    assert_eq!(Ok(()), sent_rx.recv());
+    assert_eq!(Ok(vec![1, 2]), done_rx.recv().unwrap());
+}
+
+#[test]
+pub fn test_sequence_stream_failure() {
+    let (tx, rx) = Stream::pair();
+    let vals = eventual::sequence_stream(rx).collect();
+
+    tx.send(Future::<i32, &'static str>::of(1))
+        .and_then(|tx| tx.send(Future::error("nope")))
+        .fire();
+
+    assert_eq!(Err(AsyncError::Failed("nope")), vals.await());
+}
+
+#[test]
+pub fn test_par_sequence_preserves_order() {
+    let (c1, f1) = Future::<i32, ()>::pair();
+    let (c2, f2) = Future::<i32, ()>::pair();
+    let (c3, f3) = Future::<i32, ()>::pair();
+
+    let (done_tx, done_rx) = channel();
+    eventual::par_sequence(vec![f1, f2, f3]).collect()
+        .receive(move |res| done_tx.send(res).unwrap());
+
+    // All of the futures are waited on at once
+    assert!(c1.is_ready());
+    assert!(c2.is_ready());
+    assert!(c3.is_ready());
+
+    c3.complete(3);
+    c2.complete(2);
+    assert!(done_rx.try_recv().is_err());
+
+    c1.complete(1);
+    assert_eq!(Ok(vec![1, 2, 3]), done_rx.recv().unwrap());
+}
+
+#[test]
+pub fn test_par_sequence_empty() {
+    let vals = eventual::par_sequence(Vec::<Future<i32, ()>>::new()).collect();
+    assert_eq!(Ok(vec![]), vals.await());
+}
+
+#[test]
+pub fn test_par_sequence_failure() {
+    let (c1, f1) = Future::<i32, &'static str>::pair();
+    let f2 = Future::error("nope");
+
+    let vals = eventual::par_sequence(vec![f1, f2]);
+    c1.complete(1);
+
+    let (head, rest) = vals.await().unwrap().unwrap();
+
+    assert_eq!(1, head);
+    assert_eq!(Err(AsyncError::Failed("nope")), rest.collect().await());
+}
@@ -0,0 +1,42 @@
+use eventual::*;
+use std::sync::mpsc::channel;
+
+#[test]
+pub fn test_stream_buffer_unordered_completion_order() {
+    let pairs: Vec<_> = (0..4).map(|_| Future::<i32, ()>::pair()).collect();
+    let (completes, futures): (Vec<_>, Vec<_>) = pairs.into_iter().unzip();
+    let (src, stream) = Stream::pair();
+    let (tx, rx) = channel();
+
+    src.send_all(futures).fire();
+
+    stream.buffer_unordered(2).each(move |v| tx.send(v).unwrap()).fire();
+
+    // Only the first two futures are in flight
+    let interested: Vec<bool> = completes.iter().map(|c| c.is_ready()).collect();
+    assert_eq!([true, true, false, false], &interested[..]);
+
+    let mut completes: Vec<_> = completes.into_iter().map(Some).collect();
+
+    completes[1].take().unwrap().complete(1);
+    assert_eq!(1, rx.recv().unwrap());
+    assert!(completes[2].as_ref().unwrap().is_ready());
+
+    // Completing one starts the next
+    for &i in [2, 0, 3].iter() {
+        completes[i].take().unwrap().complete(i as i32);
+    }
+
+    let vals: Vec<i32> = rx.iter().collect();
+    assert_eq!([2, 0, 3], &vals[..]);
+}
+
+#[test]
+pub fn test_stream_buffer_unordered_fail() {
+    let (tx, stream) = Stream::<Future<i32, &'static str>, &'static str>::pair();
+
+    tx.send_all(vec![Future::error("nope"), Future::of(2)]).fire();
+
+    let res = stream.buffer_unordered(2).collect().await();
+    assert_eq!(Err(AsyncError::failed("nope")), res);
+}
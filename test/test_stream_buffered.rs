@@ -0,0 +1,102 @@
+use eventual::*;
+use std::sync::mpsc::channel;
+
+#[test]
+pub fn test_stream_buffered_preserves_order() {
+    let pairs: Vec<_> = (0..4).map(|_| Future::<i32, ()>::pair()).collect();
+    let (completes, futures): (Vec<_>, Vec<_>) = pairs.into_iter().unzip();
+    let (src, stream) = Stream::pair();
+    let (tx, rx) = channel();
+
+    src.send_all(futures).fire();
+
+    stream.buffered(2).each(move |v| tx.send(v).unwrap()).fire();
+
+    // Only the first two futures are in flight
+    let interested: Vec<bool> = completes.iter().map(|c| c.is_ready()).collect();
+    assert_eq!([true, true, false, false], &interested[..]);
+
+    let mut completes: Vec<_> = completes.into_iter().map(Some).collect();
+
+    // The second value is held until the first one completes
+    completes[1].take().unwrap().complete(1);
+    assert!(rx.try_recv().is_err());
+    assert!(!completes[2].as_ref().unwrap().is_ready());
+
+    completes[0].take().unwrap().complete(0);
+    assert_eq!(0, rx.recv().unwrap());
+    assert_eq!(1, rx.recv().unwrap());
+
+    // Both slots have been freed
+    assert!(completes[2].as_ref().unwrap().is_ready());
+    assert!(completes[3].as_ref().unwrap().is_ready());
+
+    completes[3].take().unwrap().complete(3);
+    completes[2].take().unwrap().complete(2);
+
+    let vals: Vec<i32> = rx.iter().collect();
+    assert_eq!([2, 3], &vals[..]);
+}
+
+#[test]
+pub fn test_stream_buffered_fail() {
+    let (tx, stream) = Stream::<Future<i32, &'static str>, &'static str>::pair();
+
+    tx.send_all(vec![Future::of(1), Future::error("nope"), Future::of(3)]).fire();
+
+    let mut vals = stream.buffered(2);
+    let (v, rest) = vals.await().unwrap().unwrap();
+    assert_eq!(1, v);
+
+    vals = rest;
+    assert_eq!(Err(AsyncError::failed("nope")), vals.await().map(|_| ()));
+}
+
+#[test]
+pub fn test_stream_buffered_source_fail() {
+    let (tx, stream) = Stream::<Future<i32, &'static str>, &'static str>::pair();
+
+    tx.send(Future::of(1)).and_then(|tx| tx.fail("nope")).fire();
+
+    let res = stream.buffered(2).collect().await();
+    assert_eq!(Err(AsyncError::failed("nope")), res);
+}
+
+#[test]
+pub fn test_stream_buffered_fail_cancels_in_flight() {
+    let (c1, f1) = Future::<i32, &'static str>::pair();
+    let (c2, f2) = Future::<i32, &'static str>::pair();
+    let (src, stream) = Stream::pair();
+    let (tx, rx) = channel();
+
+    src.send_all(vec![f1, f2]).fire();
+
+    stream.buffered(2).collect().receive(move |res| tx.send(res).unwrap());
+    assert!(c2.is_ready());
+
+    c1.fail("nope");
+
+    assert_eq!(Err(AsyncError::failed("nope")), rx.recv().unwrap());
+    assert!(c2.is_err());
+}
+
+#[test]
+pub fn test_stream_buffered_drop_cancels_in_flight() {
+    let (c1, f1) = Future::<i32, ()>::pair();
+    let (c2, f2) = Future::<i32, ()>::pair();
+    let (src, stream) = Stream::pair();
+
+    src.send_all(vec![f1, f2]).fire();
+
+    let stream = stream.buffered(2);
+    c1.complete(1);
+
+    let (v, rest) = stream.await().unwrap().unwrap();
+
+    // The second future is still in flight
+    assert_eq!(1, v);
+    assert!(!c2.is_err());
+
+    drop(rest);
+    assert!(c2.is_err());
+}
@@ -0,0 +1,48 @@
+use eventual::*;
+use super::nums;
+
+#[test]
+pub fn test_stream_chain() {
+    let s = nums::<()>(0, 3).chain(nums(10, 13));
+
+    let vals: Vec<usize> = s.iter().collect();
+    assert_eq!([0, 1, 2, 10, 11, 12], &vals[..]);
+}
+
+#[test]
+pub fn test_stream_chain_empty() {
+    let s = Stream::empty().chain(nums::<()>(0, 2));
+    let vals: Vec<usize> = s.iter().collect();
+    assert_eq!([0, 1], &vals[..]);
+
+    let s = nums::<()>(0, 2).chain(Stream::empty());
+    let vals: Vec<usize> = s.iter().collect();
+    assert_eq!([0, 1], &vals[..]);
+}
+
+#[test]
+pub fn test_stream_chain_first_fails() {
+    let (tx1, rx1) = Stream::<i32, &'static str>::pair();
+    let (tx2, rx2) = Stream::<i32, &'static str>::pair();
+
+    tx1.send(1)
+        .and_then(|tx| tx.fail("nope"))
+        .fire();
+
+    assert_eq!(Err(AsyncError::Failed("nope")), rx1.chain(rx2).collect().await());
+
+    // The second stream was never consumed
+    assert!(tx2.is_err());
+}
+
+#[test]
+pub fn test_stream_chain_second_fails() {
+    let (tx2, rx2) = Stream::<usize, &'static str>::pair();
+
+    tx2.send(10)
+        .and_then(|tx| tx.fail("nope"))
+        .fire();
+
+    let s = nums(0, 2).chain(rx2);
+    assert_eq!(Err(AsyncError::Failed("nope")), s.collect().await());
+}
@@ -0,0 +1,22 @@
+use eventual::*;
+use super::spawn;
+
+#[test]
+pub fn test_stream_channel() {
+    let (tx, rx): (Sender<i32, ()>, Stream<i32, ()>) = channel();
+
+    spawn(move || {
+        tx.send(1).await().unwrap()
+          .send(2).await().unwrap();
+    });
+
+    assert_eq!(Ok(vec![1, 2]), rx.collect().await());
+}
+
+#[test]
+pub fn test_stream_channel_fail() {
+    let (tx, rx) = channel::<i32, &'static str>();
+    tx.fail("nope");
+
+    assert_eq!(Err(AsyncError::failed("nope")), rx.collect().await());
+}
@@ -0,0 +1,61 @@
+use eventual::*;
+use super::nums;
+
+#[test]
+pub fn test_stream_chunks() {
+    let chunks: Vec<Vec<usize>> = nums::<()>(0, 6).chunks(2).iter().collect();
+    assert_eq!(vec![vec![0, 1], vec![2, 3], vec![4, 5]], chunks);
+}
+
+#[test]
+pub fn test_stream_chunks_partial_last_batch() {
+    let chunks: Vec<Vec<usize>> = nums::<()>(0, 5).chunks(3).iter().collect();
+    assert_eq!(vec![vec![0, 1, 2], vec![3, 4]], chunks);
+}
+
+#[test]
+pub fn test_stream_chunks_empty() {
+    let chunks: Vec<Vec<usize>> = nums::<()>(0, 0).chunks(3).iter().collect();
+    assert!(chunks.is_empty());
+}
+
+#[test]
+pub fn test_stream_chunks_async() {
+    let (tx, rx) = Stream::<i32, ()>::pair();
+    let chunks = rx.chunks(2).collect();
+
+    tx.send(1)
+        .and_then(|tx| tx.send(2))
+        .and_then(|tx| tx.send(3))
+        .fire();
+
+    assert_eq!(Ok(vec![vec![1, 2], vec![3]]), chunks.await());
+}
+
+#[test]
+pub fn test_stream_chunks_failure_mid_batch() {
+    let (tx, rx) = Stream::<i32, &'static str>::pair();
+    let mut chunks = rx.chunks(2);
+
+    tx.send(1)
+        .and_then(|tx| tx.send(2))
+        .and_then(|tx| tx.send(3))
+        .and_then(|tx| tx.fail("nope"))
+        .fire();
+
+    match chunks.await() {
+        Ok(Some((chunk, rest))) => {
+            assert_eq!(vec![1, 2], chunk);
+            chunks = rest;
+        }
+        _ => panic!("unexpected result"),
+    }
+
+    assert_eq!(Err(AsyncError::Failed("nope")), chunks.await().map(|_| ()));
+}
+
+#[test]
+#[should_panic]
+pub fn test_stream_chunks_zero_size() {
+    let _ = nums::<()>(0, 5).chunks(0);
+}
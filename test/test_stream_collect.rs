@@ -1,4 +1,5 @@
 use eventual::*;
+use std::sync::mpsc::channel;
 use super::nums;
 
 #[test]
@@ -6,3 +7,46 @@ pub fn test_stream_collect_async() {
     let s = nums::<()>(0, 5).collect();
     assert_eq!(&[0, 1, 2, 3, 4], &s.await().unwrap()[..]);
 }
+
+#[test]
+pub fn test_stream_collect_empty() {
+    let s = Stream::<i32, ()>::empty().collect();
+    assert!(s.await().unwrap().is_empty());
+}
+
+#[test]
+pub fn test_stream_collect_fail_after_items() {
+    let (tx, rx) = Stream::pair();
+
+    tx.send(1)
+        .and_then(|tx| tx.send(2))
+        .and_then(|tx| tx.fail("nope"))
+        .fire();
+
+    assert_eq!(Err(AsyncError::Failed("nope")), rx.collect().await());
+}
+
+#[test]
+pub fn test_stream_collect_fail_async() {
+    let (tx, rx) = Stream::<i32, &'static str>::pair();
+    let (res_tx, res_rx) = channel();
+
+    rx.collect().receive(move |res| res_tx.send(res).unwrap());
+
+    let busy = tx.send(1);
+    assert!(res_rx.try_recv().is_err());
+
+    busy.and_then(|tx| tx.fail("nope")).fire();
+    assert_eq!(Err(AsyncError::Failed("nope")), res_rx.recv().unwrap());
+}
+
+#[test]
+pub fn test_stream_collect_aborted() {
+    let (tx, rx) = Stream::<i32, ()>::pair();
+
+    tx.send(1)
+        .and_then(|tx| tx.abort())
+        .fire();
+
+    assert_eq!(Err(AsyncError::Aborted), rx.collect().await());
+}
@@ -0,0 +1,41 @@
+use eventual::*;
+use super::{nums, spawn, sleep_ms};
+use time::Duration;
+
+#[test]
+pub fn test_stream_debounce_bursts() {
+    let (tx, rx) = Stream::<i32, ()>::pair();
+
+    spawn(move || {
+        let tx = tx.send(1).await().unwrap();
+        let tx = tx.send(2).await().unwrap();
+        let tx = tx.send(3).await().unwrap();
+        sleep_ms(200);
+        let tx = tx.send(4).await().unwrap();
+        let tx = tx.send(5).await().unwrap();
+        sleep_ms(200);
+        drop(tx);
+    });
+
+    let vals = rx.debounce(Duration::milliseconds(50)).collect().await().unwrap();
+    assert_eq!(vec![3, 5], vals);
+}
+
+#[test]
+pub fn test_stream_debounce_end_yields_held_value() {
+    let vals = nums::<()>(0, 5)
+        .debounce(Duration::seconds(10))
+        .collect().await().unwrap();
+
+    assert_eq!(vec![4], vals);
+}
+
+#[test]
+pub fn test_stream_debounce_fail() {
+    let (tx, rx) = Stream::<i32, &'static str>::pair();
+
+    tx.send(1).and_then(|tx| tx.fail("nope")).fire();
+
+    let res = rx.debounce(Duration::seconds(10)).await();
+    assert_eq!(Err(AsyncError::failed("nope")), res.map(|_| ()));
+}
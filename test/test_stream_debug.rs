@@ -0,0 +1,22 @@
+use eventual::*;
+
+#[test]
+pub fn test_stream_debug_pending() {
+    let (tx, rx) = Stream::<i32, ()>::pair();
+    assert_eq!("Stream { buffered: 0, sender_alive: true }", format!("{:?}", rx));
+
+    let _busy = tx.send(1);
+    assert_eq!("Stream { buffered: 1, sender_alive: true }", format!("{:?}", rx));
+}
+
+#[test]
+pub fn test_stream_debug_ended() {
+    let s = Stream::<i32, ()>::empty();
+    assert_eq!("Stream { buffered: 0, sender_alive: false }", format!("{:?}", s));
+
+    let s = Stream::<i32, ()>::from_result(Ok(1));
+    assert_eq!("Stream { buffered: 1, sender_alive: false }", format!("{:?}", s));
+
+    let s = Stream::<i32, &'static str>::from_result(Err("nope"));
+    assert_eq!("Stream { buffered: 0, sender_alive: false }", format!("{:?}", s));
+}
@@ -0,0 +1,25 @@
+use eventual::*;
+use super::nums;
+
+#[test]
+pub fn test_stream_enumerate() {
+    let vals: Vec<(usize, usize)> = nums::<()>(5, 8).enumerate().iter().collect();
+    assert_eq!([(0, 5), (1, 6), (2, 7)], &vals[..]);
+}
+
+#[test]
+pub fn test_stream_enumerate_empty() {
+    let vals: Vec<(usize, usize)> = Stream::<usize, ()>::empty().enumerate().iter().collect();
+    assert!(vals.is_empty());
+}
+
+#[test]
+pub fn test_stream_enumerate_fail() {
+    let (tx, rx) = Stream::<&'static str, &'static str>::pair();
+
+    tx.send("a").and_then(|tx| tx.fail("nope")).fire();
+
+    let (head, rest) = rx.enumerate().await().unwrap().unwrap();
+    assert_eq!((0, "a"), head);
+    assert_eq!(Err(AsyncError::failed("nope")), rest.await().map(|_| ()));
+}
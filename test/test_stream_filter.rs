@@ -12,3 +12,29 @@ pub fn test_stream_map_filter() {
     let vals: Vec<usize> = rx.iter().collect();
     assert_eq!([0, 2, 4], &vals[..]);
 }
+
+#[test]
+pub fn test_stream_filter_rejects_remaining() {
+    let s = nums::<()>(0, 10_000).filter(move |i| *i < 3);
+
+    let vals: Vec<usize> = s.iter().collect();
+    assert_eq!([0, 1, 2], &vals[..]);
+}
+
+#[test]
+pub fn test_stream_filter_panic_aborts() {
+    use std::thread;
+
+    let (tx, rx) = Stream::<usize, ()>::pair();
+    let (res_tx, res_rx) = channel();
+
+    rx.filter(move |i| -> bool { panic!("filter failed on {}", i) })
+        .receive(move |res| res_tx.send(res.unwrap_err()).unwrap());
+
+    let th = thread::spawn(move || {
+        tx.send(1).fire();
+    });
+
+    assert!(th.join().is_err());
+    assert_eq!(AsyncError::Aborted, res_rx.recv().unwrap());
+}
@@ -0,0 +1,32 @@
+use eventual::*;
+use super::nums;
+
+#[test]
+pub fn test_stream_first() {
+    assert_eq!(Ok(Some(0)), nums::<()>(0, 5).first().await());
+}
+
+#[test]
+pub fn test_stream_first_empty() {
+    assert_eq!(Ok(None), Stream::<i32, ()>::empty().first().await());
+}
+
+#[test]
+pub fn test_stream_first_cancels_rest() {
+    let (tx, rx) = Stream::<i32, ()>::pair();
+    let busy = tx.send(1);
+
+    assert_eq!(Ok(Some(1)), rx.first().await());
+
+    // The sender observes that the stream has been dropped
+    assert!(busy.await().is_err());
+}
+
+#[test]
+pub fn test_stream_first_fail() {
+    let (tx, rx) = Stream::<i32, &'static str>::pair();
+
+    tx.fail("nope");
+
+    assert_eq!(Err(AsyncError::failed("nope")), rx.first().await());
+}
@@ -0,0 +1,72 @@
+use eventual::*;
+use std::sync::mpsc::channel;
+use super::nums;
+
+#[test]
+pub fn test_stream_flatten() {
+    let (tx, rx) = Stream::<Stream<usize, ()>, ()>::pair();
+
+    tx.send(nums(0, 3))
+        .and_then(|tx| tx.send(Stream::empty()))
+        .and_then(|tx| tx.send(nums(10, 12)))
+        .fire();
+
+    let vals: Vec<usize> = rx.flatten().iter().collect();
+    assert_eq!([0, 1, 2, 10, 11], &vals[..]);
+}
+
+#[test]
+pub fn test_stream_flatten_is_sequential() {
+    let (tx, rx) = Stream::<Stream<i32, ()>, ()>::pair();
+    let (inner_tx1, inner_rx1) = Stream::pair();
+    let (inner_tx2, inner_rx2) = Stream::pair();
+    let (vals_tx, vals_rx) = channel();
+
+    tx.send(inner_rx1)
+        .and_then(move |tx| tx.send(inner_rx2))
+        .fire();
+
+    let busy1 = inner_tx1.send(1);
+
+    let (v, flat) = rx.flatten().await().unwrap().unwrap();
+    assert_eq!(1, v);
+
+    flat.receive(move |res| {
+        let (v, _) = res.unwrap().unwrap();
+        vals_tx.send(v).unwrap();
+    });
+
+    // The second stream is not requested until the first one is done
+    assert!(!inner_tx2.is_ready());
+
+    busy1.and_then(|tx| drop(tx)).fire();
+    assert!(inner_tx2.is_ready());
+
+    inner_tx2.send(2).fire();
+    assert_eq!(2, vals_rx.recv().unwrap());
+}
+
+#[test]
+pub fn test_stream_flatten_outer_fail() {
+    let (tx, rx) = Stream::<Stream<usize, &'static str>, &'static str>::pair();
+
+    tx.send(nums(0, 2))
+        .and_then(|tx| tx.fail("outer"))
+        .fire();
+
+    assert_eq!(Err(AsyncError::Failed("outer")), rx.flatten().collect().await());
+}
+
+#[test]
+pub fn test_stream_flatten_inner_fail() {
+    let (tx, rx) = Stream::<Stream<i32, &'static str>, &'static str>::pair();
+    let (inner_tx, inner_rx) = Stream::pair();
+
+    inner_tx.send(1)
+        .and_then(|tx| tx.fail("inner"))
+        .fire();
+
+    tx.send(inner_rx).fire();
+
+    assert_eq!(Err(AsyncError::Failed("inner")), rx.flatten().collect().await());
+}
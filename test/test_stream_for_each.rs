@@ -0,0 +1,83 @@
+use eventual::*;
+use super::nums;
+use std::sync::mpsc::channel;
+
+#[test]
+pub fn test_stream_for_each() {
+    let (tx, rx) = channel();
+
+    let res = nums::<()>(0, 5)
+        .for_each(move |v| {
+            tx.send(v).unwrap();
+            Future::of(v * 2)
+        })
+        .await();
+
+    assert_eq!(Ok(()), res);
+
+    let vals: Vec<usize> = rx.iter().collect();
+    assert_eq!(&[0, 1, 2, 3, 4], &vals[..]);
+}
+
+#[test]
+pub fn test_stream_for_each_waits_for_async() {
+    let (tx, rx) = channel();
+    let (c1, f1) = Future::<(), ()>::pair();
+    let mut pending = Some(f1);
+
+    let res = nums::<()>(0, 3).for_each(move |v| {
+        tx.send(v).unwrap();
+
+        match pending.take() {
+            Some(f) => f,
+            None => Future::of(()),
+        }
+    });
+
+    let res = res.shared();
+    res.clone().fire();
+
+    // The second value is not requested until the first action completes
+    assert_eq!(Ok(0), rx.recv());
+    assert!(rx.try_recv().is_err());
+
+    c1.complete(());
+    assert_eq!(Ok(()), res.await());
+
+    let vals: Vec<usize> = rx.iter().collect();
+    assert_eq!(&[1, 2], &vals[..]);
+}
+
+#[test]
+pub fn test_stream_for_each_action_fails() {
+    let (tx, rx) = channel();
+
+    let res = nums::<&'static str>(0, 5)
+        .for_each(move |v| {
+            tx.send(v).unwrap();
+
+            if v == 2 {
+                Future::error("nope")
+            } else {
+                Future::of(())
+            }
+        })
+        .await();
+
+    assert_eq!(Err(AsyncError::Failed("nope")), res);
+
+    let vals: Vec<usize> = rx.iter().collect();
+    assert_eq!(&[0, 1, 2], &vals[..]);
+}
+
+#[test]
+pub fn test_stream_for_each_stream_fails() {
+    let (tx, rx) = Stream::<i32, &'static str>::pair();
+
+    tx.send(1)
+        .and_then(|tx| tx.fail("nope"))
+        .fire();
+
+    let res = rx.for_each(|_| Future::of(())).await();
+    assert_eq!(Err(AsyncError::Failed("nope")), res);
+}
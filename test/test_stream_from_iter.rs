@@ -0,0 +1,36 @@
+use eventual::*;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[test]
+pub fn test_stream_from_iter() {
+    let stream = Stream::<i32, ()>::from_iter(vec![1, 2, 3]);
+    assert_eq!(Ok(vec![1, 2, 3]), stream.collect().await());
+}
+
+#[test]
+pub fn test_stream_from_iter_empty() {
+    let stream = Stream::<i32, ()>::from_iter(vec![]);
+    assert_eq!(Ok(vec![]), stream.collect().await());
+}
+
+#[test]
+pub fn test_stream_from_iter_is_lazy() {
+    let pulled = Arc::new(AtomicUsize::new(0));
+    let p = pulled.clone();
+
+    let stream = Stream::<usize, ()>::from_iter((0..).map(move |i| {
+        p.fetch_add(1, Ordering::SeqCst);
+        i
+    }));
+
+    // Nothing is pulled until the consumer is ready
+    assert_eq!(0, pulled.load(Ordering::SeqCst));
+
+    let (head, rest) = stream.await().unwrap().unwrap();
+    assert_eq!(0, head);
+    assert_eq!(1, pulled.load(Ordering::SeqCst));
+
+    assert_eq!(Ok(vec![1, 2, 3]), rest.take(3).collect().await());
+    assert!(pulled.load(Ordering::SeqCst) <= 5);
+}
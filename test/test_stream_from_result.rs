@@ -0,0 +1,15 @@
+use eventual::*;
+
+#[test]
+pub fn test_stream_from_result_ok() {
+    let s = Stream::<i32, &'static str>::from_result(Ok(123));
+    assert_eq!(Ok(vec![123]), s.collect().await());
+}
+
+#[test]
+pub fn test_stream_from_result_err() {
+    let s = Stream::<i32, &'static str>::from_result(Err("nope"));
+
+    assert!(s.is_err());
+    assert_eq!(Err(AsyncError::Failed("nope")), s.collect().await());
+}
@@ -1,4 +1,5 @@
 use eventual::*;
+use super::nums;
 
 #[test]
 pub fn test_stream_iter_async_producer() {
@@ -21,3 +22,25 @@ pub fn test_stream_iter_async_producer() {
     let vals: Vec<i32> = rx.iter().collect();
     assert_eq!([0, 1, 2, 3, 4], &vals[..]);
 }
+
+#[test]
+pub fn test_stream_iter_rev() {
+    let s = nums::<()>(0, 5);
+
+    let vals: Vec<usize> = s.iter().rev().collect();
+    assert_eq!([4, 3, 2, 1, 0], &vals[..]);
+}
+
+#[test]
+pub fn test_stream_iter_both_ends() {
+    let s = nums::<()>(0, 5);
+    let mut iter = s.iter();
+
+    assert_eq!(Some(0), iter.next());
+    assert_eq!(Some(4), iter.next_back());
+    assert_eq!(Some(1), iter.next());
+    assert_eq!(Some(3), iter.next_back());
+    assert_eq!(Some(2), iter.next());
+    assert_eq!(None, iter.next_back());
+    assert_eq!(None, iter.next());
+}
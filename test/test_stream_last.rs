@@ -0,0 +1,21 @@
+use eventual::*;
+use super::nums;
+
+#[test]
+pub fn test_stream_last() {
+    assert_eq!(Ok(Some(4)), nums::<()>(0, 5).last().await());
+}
+
+#[test]
+pub fn test_stream_last_empty() {
+    assert_eq!(Ok(None), Stream::<i32, ()>::empty().last().await());
+}
+
+#[test]
+pub fn test_stream_last_fail() {
+    let (tx, rx) = Stream::<i32, &'static str>::pair();
+
+    tx.send(1).and_then(|tx| tx.fail("nope")).fire();
+
+    assert_eq!(Err(AsyncError::failed("nope")), rx.last().await());
+}
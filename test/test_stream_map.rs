@@ -24,3 +24,89 @@ pub fn test_stream_map_async() {
     let vals: Vec<usize> = rx.iter().collect();
     assert_eq!([0, 2, 4, 6, 8], &vals[..]);
 }
+
+#[test]
+pub fn test_stream_map_ends_early() {
+    let (tx, rx) = Stream::<usize, ()>::pair();
+
+    tx.send(1)
+        .and_then(|tx| tx.send(2))
+        .and_then(|tx| drop(tx))
+        .fire();
+
+    let vals: Vec<usize> = rx.map(move |i| i * 10).iter().collect();
+    assert_eq!([10, 20], &vals[..]);
+}
+
+#[test]
+pub fn test_stream_map_panic_aborts() {
+    use std::thread;
+
+    let (tx, rx) = Stream::<usize, ()>::pair();
+    let (res_tx, res_rx) = channel();
+
+    rx.map(move |i| -> usize { panic!("map failed on {}", i) })
+        .receive(move |res| res_tx.send(res.is_err()).unwrap());
+
+    let th = thread::spawn(move || {
+        tx.send(1).fire();
+    });
+
+    assert!(th.join().is_err());
+    assert!(res_rx.recv().unwrap());
+}
+
+#[test]
+pub fn test_stream_map_async_spawned() {
+    let s = nums::<()>(0, 5).map_async(|v| Future::spawn(move || v * 3));
+
+    let vals: Vec<usize> = s.iter().collect();
+    assert_eq!([0, 3, 6, 9, 12], &vals[..]);
+}
+
+#[test]
+pub fn test_stream_map_async_waits_for_each_value() {
+    let (tx, rx) = Stream::<i32, ()>::pair();
+    let (seen_tx, seen_rx) = channel();
+    let (completes_tx, completes_rx) = channel();
+
+    tx.send(1)
+        .and_then(|tx| tx.send(2))
+        .fire();
+
+    let s = rx.map_async(move |v| {
+        let (c, f) = Future::<i32, ()>::pair();
+        seen_tx.send(v).unwrap();
+        completes_tx.send(c).unwrap();
+        f
+    });
+
+    let (vals_tx, vals_rx) = channel();
+    s.each(move |v| vals_tx.send(v).unwrap()).fire();
+
+    assert_eq!(1, seen_rx.recv().unwrap());
+    let c1 = completes_rx.recv().unwrap();
+
+    // The second value is not processed until the first one is realized
+    assert!(seen_rx.try_recv().is_err());
+
+    c1.complete(10);
+    assert_eq!(10, vals_rx.recv().unwrap());
+
+    assert_eq!(2, seen_rx.recv().unwrap());
+    completes_rx.recv().unwrap().complete(20);
+    assert_eq!(20, vals_rx.recv().unwrap());
+}
+
+#[test]
+pub fn test_stream_map_async_fail() {
+    let s = nums::<&'static str>(0, 5).map_async(|v| {
+        if v == 2 {
+            Err("nope")
+        } else {
+            Ok(v)
+        }
+    });
+
+    assert_eq!(Err(AsyncError::Failed("nope")), s.collect().await());
+}
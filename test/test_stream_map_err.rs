@@ -41,3 +41,27 @@ pub fn test_stream_map_err_fail() {
         _ => panic!("unexpected value"),
     }
 }
+
+#[derive(Debug, PartialEq)]
+struct WrappedError(&'static str);
+
+impl From<&'static str> for WrappedError {
+    fn from(e: &'static str) -> WrappedError {
+        WrappedError(e)
+    }
+}
+
+#[test]
+pub fn test_stream_error_into() {
+    let (tx, rx) = Stream::<i32, &'static str>::pair();
+
+    tx.send(123)
+        .and_then(|tx| tx.fail("win"))
+        .fire();
+
+    let rx: Stream<i32, WrappedError> = rx.error_into();
+    let (v, rest) = rx.await().unwrap().unwrap();
+
+    assert_eq!(123, v);
+    assert_eq!(Err(AsyncError::failed(WrappedError("win"))), rest.await().map(|_| ()));
+}
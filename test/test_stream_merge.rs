@@ -0,0 +1,75 @@
+use eventual::*;
+use super::nums;
+use std::sync::mpsc::channel;
+
+#[test]
+pub fn test_stream_merge() {
+    let (tx1, rx1) = Stream::<i32, ()>::pair();
+    let (tx2, rx2) = Stream::<&'static str, ()>::pair();
+    let (tx, rx) = channel();
+
+    rx1.merge(rx2).each(move |v| tx.send(v).unwrap()).fire();
+
+    let tx2 = tx2.send("one").await().unwrap();
+    assert_eq!(Either::Right("one"), rx.recv().unwrap());
+
+    let tx1 = tx1.send(1).await().unwrap();
+    assert_eq!(Either::Left(1), rx.recv().unwrap());
+
+    let tx1 = tx1.send(2).await().unwrap();
+    assert_eq!(Either::Left(2), rx.recv().unwrap());
+
+    drop(tx1);
+
+    tx2.send("two").await().unwrap();
+    assert_eq!(Either::Right("two"), rx.recv().unwrap());
+
+    assert!(rx.recv().is_err());
+}
+
+#[test]
+pub fn test_stream_merge_yields_all_values() {
+    let vals = nums::<()>(0, 5).merge(nums(10, 13)).collect().await().unwrap();
+
+    let left: Vec<usize> = vals.iter().filter_map(|v| v.left()).collect();
+    let right: Vec<usize> = vals.iter().filter_map(|v| v.right()).collect();
+
+    assert_eq!([0, 1, 2, 3, 4], &left[..]);
+    assert_eq!([10, 11, 12], &right[..]);
+}
+
+#[test]
+pub fn test_stream_merge_alternates_ready_streams() {
+    let vals = nums::<()>(0, 3).merge(nums(10, 13)).collect().await().unwrap();
+
+    // Neither stream, being always ready, starves the other
+    let sides: Vec<bool> = vals.iter().map(|v| v.is_left()).collect();
+    assert_eq!([true, false, true, false, true, false], &sides[..]);
+}
+
+#[test]
+pub fn test_stream_merge_ends_when_both_end() {
+    let (tx1, rx1) = Stream::<i32, ()>::pair();
+    let (tx2, rx2) = Stream::<i32, ()>::pair();
+    let (tx, rx) = channel();
+
+    rx1.merge(rx2).collect().receive(move |res| tx.send(res).unwrap());
+
+    drop(tx1);
+    assert!(rx.try_recv().is_err());
+
+    tx2.send(1).await().unwrap();
+    assert_eq!(Ok(vec![Either::Right(1)]), rx.recv().unwrap());
+}
+
+#[test]
+pub fn test_stream_merge_fails_immediately() {
+    let (tx1, rx1) = Stream::<i32, &'static str>::pair();
+    let (tx2, rx2) = Stream::<i32, &'static str>::pair();
+
+    let merged = rx1.merge(rx2);
+    tx2.fail("nope");
+
+    assert_eq!(Err(AsyncError::Failed("nope")), merged.collect().await());
+    assert!(tx1.is_err());
+}
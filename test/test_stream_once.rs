@@ -0,0 +1,36 @@
+use eventual::*;
+use super::{spawn, sleep_ms};
+
+#[test]
+pub fn test_stream_once() {
+    let stream = Stream::once(Future::<i32, ()>::of(1));
+    assert_eq!(Ok(vec![1]), stream.collect().await());
+}
+
+#[test]
+pub fn test_stream_once_async() {
+    let (c, f) = Future::<i32, ()>::pair();
+
+    spawn(move || {
+        sleep_ms(50);
+        c.complete(2);
+    });
+
+    let (head, rest) = Stream::once(f).await().unwrap().unwrap();
+    assert_eq!(2, head);
+    assert_eq!(Ok(None), rest.await().map(|head| head.map(|(v, _)| v)));
+}
+
+#[test]
+pub fn test_stream_once_fail() {
+    let stream = Stream::once(Future::<i32, &'static str>::error("nope"));
+    assert_eq!(Err(AsyncError::failed("nope")), stream.collect().await());
+}
+
+#[test]
+pub fn test_stream_once_abort() {
+    let (c, f) = Future::<i32, ()>::pair();
+    drop(c);
+
+    assert!(Stream::once(f).collect().await().unwrap_err().is_aborted());
+}
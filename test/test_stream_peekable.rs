@@ -0,0 +1,69 @@
+use eventual::*;
+use super::nums;
+
+#[test]
+pub fn test_stream_peek_does_not_consume() {
+    let s = nums::<()>(0, 3).peekable();
+
+    let s = s.peek().await().unwrap();
+    assert_eq!(Some(&0), s.peeked());
+
+    // Peeking again does not advance the stream
+    let s = s.peek().await().unwrap();
+    assert_eq!(Some(&0), s.peeked());
+
+    let (v, s) = s.next().await().unwrap().unwrap();
+    assert_eq!(0, v);
+    assert_eq!(None, s.peeked());
+
+    let s = s.peek().await().unwrap();
+    assert_eq!(Some(&1), s.peeked());
+
+    assert_eq!(Ok(vec![1, 2]), s.into_stream().collect().await());
+}
+
+#[test]
+pub fn test_stream_peek_end() {
+    let s = nums::<()>(0, 0).peekable();
+    assert!(!s.is_done());
+
+    let s = s.peek().await().unwrap();
+    assert_eq!(None, s.peeked());
+    assert!(s.is_done());
+
+    assert!(s.next().await().unwrap().is_none());
+}
+
+#[test]
+pub fn test_stream_peekable_next_without_peek() {
+    let s = nums::<()>(0, 2).peekable();
+
+    let (v, s) = s.next().await().unwrap().unwrap();
+    assert_eq!(0, v);
+
+    let (v, s) = s.next().await().unwrap().unwrap();
+    assert_eq!(1, v);
+
+    assert!(s.next().await().unwrap().is_none());
+}
+
+#[test]
+pub fn test_stream_peek_failure() {
+    let (tx, rx) = Stream::<i32, &'static str>::pair();
+    let s = rx.peekable();
+
+    tx.fail("nope");
+
+    assert_eq!(Err(AsyncError::Failed("nope")), s.peek().await().map(|_| ()));
+}
+
+#[test]
+pub fn test_stream_peek_async() {
+    let (tx, rx) = Stream::<i32, ()>::pair();
+    let peeked = rx.peekable().peek();
+
+    tx.send(1).fire();
+
+    let s = peeked.await().unwrap();
+    assert_eq!(Some(&1), s.peeked());
+}
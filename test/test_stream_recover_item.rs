@@ -0,0 +1,35 @@
+use eventual::*;
+use super::nums;
+
+#[test]
+pub fn test_stream_recover_item_success() {
+    let vals: Vec<usize> = nums::<()>(0, 3)
+        .recover_item(|_| panic!("nope"))
+        .iter().collect();
+
+    assert_eq!(&[0, 1, 2], &vals[..]);
+}
+
+#[test]
+pub fn test_stream_recover_item_replaces_error() {
+    let (tx, rx) = Stream::<i32, &'static str>::pair();
+    let vals = rx.recover_item(|e| Some(e.len() as i32)).collect();
+
+    tx.send(1)
+        .and_then(|tx| tx.fail("nope"))
+        .fire();
+
+    assert_eq!(Ok(vec![1, 4]), vals.await());
+}
+
+#[test]
+pub fn test_stream_recover_item_ends_stream() {
+    let (tx, rx) = Stream::<i32, &'static str>::pair();
+    let vals = rx.recover_item(|_| None).collect();
+
+    tx.send(1)
+        .and_then(|tx| tx.fail("nope"))
+        .fire();
+
+    assert_eq!(Ok(vec![1]), vals.await());
+}
@@ -31,3 +31,39 @@ pub fn test_stream_reduce_async_success() {
 pub fn test_stream_reduce_async_fail() {
     // An async computation returned from a reduction fails
 }
+
+/*
+ *
+ * ===== Stream::fold(...) =====
+ *
+ */
+
+#[test]
+pub fn test_stream_fold() {
+    let mut calls = 0;
+
+    let s = nums::<()>(0, 5).fold(vec![], move |mut acc, v| {
+        calls += 1;
+        acc.push((calls, v));
+        acc
+    });
+
+    assert_eq!(&[(1, 0), (2, 1), (3, 2), (4, 3), (5, 4)], &s.await().unwrap()[..]);
+}
+
+#[test]
+pub fn test_stream_fold_empty() {
+    let s = Stream::<usize, ()>::empty().fold(123, |_, _| unreachable!());
+    assert_eq!(123, s.await().unwrap());
+}
+
+#[test]
+pub fn test_stream_fold_fail() {
+    let (tx, rx) = Stream::pair();
+    tx.send(1)
+        .and_then(|tx| tx.fail("nope"))
+        .fire();
+
+    let folded = rx.fold(0, move |sum, v| sum + v);
+    assert_eq!(Err(AsyncError::Failed("nope")), folded.await());
+}
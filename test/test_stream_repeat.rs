@@ -0,0 +1,30 @@
+use eventual::*;
+
+#[test]
+pub fn test_stream_repeat() {
+    let stream = Stream::<String, ()>::repeat("hi".to_string());
+    let vals = stream.take(3).collect().await().unwrap();
+
+    assert_eq!(vec!["hi", "hi", "hi"], vals);
+}
+
+#[test]
+pub fn test_stream_repeat_iter() {
+    let mut iter = Stream::<i32, ()>::repeat(1).iter();
+
+    for _ in 0..100 {
+        assert_eq!(Some(1), iter.next());
+    }
+}
+
+#[test]
+pub fn test_stream_repeat_n() {
+    let stream = Stream::<i32, ()>::repeat_n(5, 4);
+    assert_eq!(Ok(vec![5, 5, 5, 5]), stream.collect().await());
+}
+
+#[test]
+pub fn test_stream_repeat_n_zero() {
+    let stream = Stream::<i32, ()>::repeat_n(5, 0);
+    assert_eq!(Ok(vec![]), stream.collect().await());
+}
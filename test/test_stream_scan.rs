@@ -0,0 +1,56 @@
+use eventual::*;
+use super::nums;
+
+#[test]
+pub fn test_stream_scan() {
+    let vals: Vec<usize> = nums::<()>(1, 5)
+        .scan(0, |sum, v| {
+            *sum += v;
+            Some(*sum)
+        })
+        .iter().collect();
+
+    assert_eq!(&[1, 3, 6, 10], &vals[..]);
+}
+
+#[test]
+pub fn test_stream_scan_ends_early() {
+    let vals: Vec<String> = nums::<()>(0, 10)
+        .scan(1, |factor, v| {
+            *factor *= 2;
+
+            if v < 3 {
+                Some(format!("{}", v * *factor))
+            } else {
+                None
+            }
+        })
+        .iter().collect();
+
+    assert_eq!(&["0", "4", "16"], &vals[..]);
+}
+
+#[test]
+pub fn test_stream_scan_ends_early_cancels_original() {
+    let (tx, rx) = Stream::<i32, ()>::pair();
+    let vals = rx.scan((), |_, v| if v < 2 { Some(v) } else { None }).collect();
+
+    let busy = tx.send(1).and_then(|tx| tx.send(2)).and_then(|tx| tx.send(3));
+    let (c, done) = Future::<bool, ()>::pair();
+    busy.receive(move |res| c.complete(res.is_err()));
+
+    assert_eq!(Ok(vec![1]), vals.await());
+    assert_eq!(Ok(true), done.await());
+}
+
+#[test]
+pub fn test_stream_scan_failure() {
+    let (tx, rx) = Stream::<i32, &'static str>::pair();
+    let vals = rx.scan(0, |sum, v| { *sum += v; Some(*sum) }).collect();
+
+    tx.send(1)
+        .and_then(|tx| tx.fail("nope"))
+        .fire();
+
+    assert_eq!(Err(AsyncError::Failed("nope")), vals.await());
+}
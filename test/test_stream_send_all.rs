@@ -97,3 +97,51 @@ pub fn test_future_send_fail() {
     let vals: Vec<usize> = rx.iter().collect();
     assert_eq!(&[1, 10, 3], &vals[..]);
 }
+
+#[test]
+pub fn test_iter_send_success() {
+    let (tx, rx) = Stream::<usize, ()>::pair();
+
+    tx.send(1)
+        .and_then(|tx| {
+            tx.send_all(vec![2, 3, 4])
+                .map_err(|_| unreachable!())
+        })
+        .and_then(|tx| tx.send(5))
+        .fire();
+
+    let vals: Vec<usize> = rx.iter().collect();
+    assert_eq!(&[1, 2, 3, 4, 5], &vals[..]);
+}
+
+#[test]
+pub fn test_iter_send_empty() {
+    let (tx, rx) = Stream::<usize, ()>::pair();
+
+    tx.send_all(Vec::new())
+        .map_err(|_| unreachable!())
+        .and_then(|tx| tx.send(1))
+        .fire();
+
+    let vals: Vec<usize> = rx.iter().collect();
+    assert_eq!(&[1], &vals[..]);
+}
+
+#[test]
+pub fn test_iter_send_consumer_loses_interest() {
+    let (seen, rx) = channel();
+    let (tx, dst) = Stream::<usize, ()>::pair();
+
+    let src = (1..10).map(move |v| {
+        seen.send(v).unwrap();
+        v
+    });
+
+    let _ = tx.send_all(src);
+
+    let vals: Vec<usize> = dst.take(3).iter().collect();
+    assert_eq!(&[1, 2, 3], &vals[..]);
+
+    let vals: Vec<usize> = rx.iter().collect();
+    assert_eq!(&[1, 2, 3], &vals[..]);
+}
@@ -0,0 +1,104 @@
+use eventual::*;
+use super::{spawn, sleep_ms};
+
+#[test]
+pub fn test_shared_sender_single_producer() {
+    let (tx, rx) = Stream::<i32, ()>::pair();
+    let tx = tx.shared();
+
+    spawn(move || {
+        for i in 0..5 {
+            tx.send(i).await().unwrap();
+        }
+    });
+
+    assert_eq!(Ok(vec![0, 1, 2, 3, 4]), rx.collect().await());
+}
+
+#[test]
+pub fn test_shared_sender_fan_in() {
+    let (tx, rx) = Stream::<usize, ()>::pair();
+    let tx = tx.shared();
+
+    for i in 0..4 {
+        let tx = tx.clone();
+
+        spawn(move || {
+            for j in 0..10 {
+                tx.send(i * 10 + j).await().unwrap();
+            }
+        });
+    }
+
+    drop(tx);
+
+    let mut vals = rx.collect().await().unwrap();
+    vals.sort();
+
+    assert_eq!((0..40).collect::<Vec<usize>>(), vals);
+}
+
+#[test]
+pub fn test_shared_sender_ends_when_all_clones_dropped() {
+    let (tx, rx) = Stream::<i32, ()>::pair();
+    let tx = tx.shared();
+    let tx2 = tx.clone();
+
+    tx.send(1).fire();
+    drop(tx);
+
+    // Still open since a clone is alive
+    let (head, rx) = rx.await().unwrap().unwrap();
+    assert_eq!(1, head);
+    assert!(!rx.is_ready());
+
+    spawn(move || {
+        sleep_ms(50);
+        tx2.send(2).fire();
+    });
+
+    assert_eq!(Ok(vec![2]), rx.collect().await());
+}
+
+#[test]
+pub fn test_shared_sender_send_without_waiting() {
+    let (tx, rx) = Stream::<i32, ()>::pair();
+    let tx = tx.shared();
+
+    let sends: Vec<_> = (0..3).map(|i| tx.send(i)).collect();
+    drop(tx);
+
+    assert_eq!(Ok(vec![0, 1, 2]), rx.collect().await());
+
+    for send in sends {
+        assert_eq!(Ok(()), send.await());
+    }
+}
+
+#[test]
+pub fn test_shared_sender_fail() {
+    let (tx, rx) = Stream::<i32, &'static str>::pair();
+    let tx = tx.shared();
+    let tx2 = tx.clone();
+
+    tx.send(1).fire();
+    tx.fail("nope");
+
+    // Sends after the failure are rejected
+    assert!(tx2.send(2).await().is_err());
+
+    let (head, rx) = rx.await().unwrap().unwrap();
+    assert_eq!(1, head);
+    assert_eq!(Err(AsyncError::failed("nope")), rx.collect().await());
+}
+
+#[test]
+pub fn test_shared_sender_stream_dropped() {
+    let (tx, rx) = Stream::<i32, ()>::pair();
+    let tx = tx.shared();
+
+    drop(rx);
+
+    assert!(tx.send(1).await().is_err());
+    assert!(tx.send(2).await().is_err());
+}
@@ -0,0 +1,54 @@
+use eventual::*;
+use super::nums;
+
+#[test]
+pub fn test_stream_skip() {
+    let stream = nums::<()>(0, 10).skip(6);
+    let vals: Vec<usize> = stream.iter().collect();
+
+    assert_eq!([6, 7, 8, 9], &vals[..]);
+}
+
+#[test]
+pub fn test_stream_skip_zero() {
+    let vals: Vec<usize> = nums::<()>(0, 3).skip(0).iter().collect();
+    assert_eq!([0, 1, 2], &vals[..]);
+}
+
+#[test]
+pub fn test_stream_skip_past_end() {
+    let stream = nums::<()>(0, 3).skip(10);
+    assert!(stream.iter().next().is_none());
+}
+
+#[test]
+pub fn test_stream_skip_lazy() {
+    let (tx, rx) = Stream::<i32, ()>::pair();
+    let _skipped = rx.skip(1);
+
+    assert!(!tx.is_ready());
+}
+
+#[test]
+pub fn test_stream_skip_fail_while_skipping() {
+    let (tx, rx) = Stream::<i32, &'static str>::pair();
+
+    tx.send(1)
+        .and_then(|tx| tx.fail("nope"))
+        .fire();
+
+    assert_eq!(Err(AsyncError::Failed("nope")), rx.skip(3).to_future().await().map(|_| ()));
+}
+
+#[test]
+pub fn test_stream_skip_fail_while_forwarding() {
+    let (tx, rx) = Stream::<i32, &'static str>::pair();
+
+    tx.send(1)
+        .and_then(|tx| tx.send(2))
+        .and_then(|tx| tx.fail("nope"))
+        .fire();
+
+    let res = rx.skip(1).fold(vec![], |mut vals, v| { vals.push(v); vals }).await();
+    assert_eq!(Err(AsyncError::Failed("nope")), res);
+}
@@ -0,0 +1,109 @@
+use eventual::*;
+use super::nums;
+use std::sync::mpsc::{channel, Receiver};
+
+fn collect<T: Send, E: Send>(stream: Stream<T, E>) -> Receiver<AsyncResult<Vec<T>, E>> {
+    let (tx, rx) = channel();
+    stream.collect().receive(move |res| tx.send(res).unwrap());
+    rx
+}
+
+#[test]
+pub fn test_stream_split() {
+    let (even, odd) = nums::<()>(0, 7).split(|v| v % 2 == 0);
+
+    let even = collect(even);
+    let odd = collect(odd);
+
+    assert_eq!(Ok(vec![0, 2, 4, 6]), even.recv().unwrap());
+    assert_eq!(Ok(vec![1, 3, 5]), odd.recv().unwrap());
+}
+
+#[test]
+pub fn test_stream_split_buffers_for_slow_consumer() {
+    let (small, large) = nums::<()>(0, 6).split(|v| *v < 3);
+
+    // Reading the large values first buffers the small ones
+    let large: Vec<usize> = large.iter().collect();
+    assert_eq!(&[3, 4, 5], &large[..]);
+
+    assert_eq!(Ok(vec![0, 1, 2]), small.collect().await());
+}
+
+#[test]
+pub fn test_stream_split_dropped_output() {
+    let (seen_tx, seen_rx) = channel();
+
+    let src = nums::<()>(0, 6).map(move |v| {
+        seen_tx.send(v).unwrap();
+        v
+    });
+
+    let (even, odd) = src.split(|v| v % 2 == 0);
+    drop(odd);
+
+    assert_eq!(Ok(vec![0, 2, 4]), even.collect().await());
+
+    let seen: Vec<usize> = seen_rx.iter().collect();
+    assert_eq!(&[0, 1, 2, 3, 4, 5], &seen[..]);
+}
+
+#[test]
+pub fn test_stream_split_both_dropped_cancels_source() {
+    let (tx, rx) = Stream::<i32, ()>::pair();
+    let (a, b) = rx.split(|v| *v > 0);
+
+    let a = a.take(1).collect();
+
+    let (done_tx, done_rx) = channel();
+    tx.send(1)
+        .and_then(|tx| tx.send(2))
+        .receive(move |res| done_tx.send(res.is_err()).unwrap());
+
+    assert_eq!(Ok(vec![1]), a.await());
+    assert!(done_rx.try_recv().is_err());
+
+    // Dropping the second output cancels the source
+    drop(b);
+    assert_eq!(Ok(true), done_rx.recv());
+}
+
+#[test]
+pub fn test_stream_split_failure() {
+    let (tx, rx) = Stream::<i32, &'static str>::pair();
+    let (a, b) = rx.split(|v| *v > 0);
+
+    let a = collect(a);
+    let b = collect(b);
+
+    tx.send(1)
+        .and_then(|tx| tx.send(-1))
+        .and_then(|tx| tx.fail("nope"))
+        .fire();
+
+    assert_eq!(Err(AsyncError::Failed("nope")), a.recv().unwrap());
+    assert_eq!(Err(AsyncError::Failed("nope")), b.recv().unwrap());
+}
+
+#[test]
+pub fn test_stream_split_panicking_predicate_does_not_poison() {
+    use std::thread;
+
+    let (tx, rx) = Stream::<i32, ()>::pair();
+    let (evens, odds) = rx.split(|v| {
+        if *v == 2 {
+            panic!("boom");
+        }
+
+        v % 2 == 0
+    });
+
+    // Request a value so that the source is pulled
+    evens.receive(|_| {});
+
+    let res = thread::spawn(move || { tx.send(2).fire(); }).join();
+    assert!(res.is_err());
+
+    // The other output can still be used without hitting a poisoned lock
+    drop(odds);
+}
@@ -1,4 +1,5 @@
-use eventual::Future;
+use eventual::*;
+use std::sync::mpsc::{self, channel};
 use super::nums;
 
 #[test]
@@ -9,6 +10,36 @@ pub fn test_stream_take() {
     assert_eq!([0, 1, 2, 3], &vals[..]);
 }
 
+#[test]
+pub fn test_stream_take_zero() {
+    let stream = nums::<()>(0, 10).take(0);
+    assert!(stream.iter().next().is_none());
+}
+
+#[test]
+pub fn test_stream_take_cancels_producer() {
+    let (tx, rx) = Stream::<usize, ()>::pair();
+    let (done_tx, done_rx) = channel();
+
+    fn produce<A>(sender: A, i: usize, done: mpsc::Sender<usize>)
+            where A: Async<Value=Sender<usize, ()>> {
+        sender.receive(move |res| {
+            match res {
+                Ok(sender) => produce(sender.send(i), i + 1, done),
+                Err(_) => done.send(i).unwrap(),
+            }
+        });
+    }
+
+    produce(tx, 0, done_tx);
+
+    let vals: Vec<usize> = rx.take(3).iter().collect();
+    assert_eq!([0, 1, 2], &vals[..]);
+
+    // The producer is notified that there is no more interest
+    assert_eq!(3, done_rx.recv().unwrap());
+}
+
 /*
  *
  * ===== Stream::take_until(...) =====
@@ -29,3 +60,25 @@ pub fn test_stream_take_until() {
     c.complete("done");
     assert!(iter.next().is_none());
 }
+
+#[test]
+pub fn test_stream_take_until_cond_fails() {
+    let (c, f) = Future::<(), &'static str>::pair();
+    let stream = nums(0, 1_000_000).take_until(f);
+
+    c.fail("nope");
+
+    assert_eq!(Err(AsyncError::failed("nope")), stream.await().map(|_| ()));
+}
+
+#[test]
+pub fn test_stream_take_until_stream_fails() {
+    let (tx, rx) = Stream::<i32, &'static str>::pair();
+    let (_c, f) = Future::<(), &'static str>::pair();
+
+    tx.send(1).and_then(|tx| tx.fail("nope")).fire();
+
+    let (v, rest) = rx.take_until(f).await().unwrap().unwrap();
+    assert_eq!(1, v);
+    assert_eq!(Err(AsyncError::failed("nope")), rest.await().map(|_| ()));
+}
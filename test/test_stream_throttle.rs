@@ -0,0 +1,52 @@
+use eventual::*;
+use super::nums;
+use time::{Duration, SteadyTime};
+
+#[test]
+pub fn test_stream_throttle() {
+    let start = SteadyTime::now();
+    let vals = nums::<()>(0, 4)
+        .throttle(Duration::milliseconds(100))
+        .collect().await().unwrap();
+
+    assert_eq!(vec![0, 1, 2, 3], vals);
+    assert!(SteadyTime::now() - start >= Duration::milliseconds(290));
+}
+
+#[test]
+pub fn test_stream_throttle_delivers_first_value_immediately() {
+    let start = SteadyTime::now();
+    let head = nums::<()>(0, 4)
+        .throttle(Duration::seconds(10))
+        .await().unwrap();
+
+    assert_eq!(0, head.unwrap().0);
+    assert!(SteadyTime::now() - start < Duration::seconds(1));
+}
+
+#[test]
+pub fn test_stream_throttle_slow_consumer() {
+    let mut vals = nums::<()>(0, 3)
+        .throttle(Duration::milliseconds(50))
+        .iter();
+
+    assert_eq!(Some(0), vals.next());
+    super::sleep_ms(100);
+
+    // The slow consumer already waited longer than the throttle duration
+    let start = SteadyTime::now();
+    assert_eq!(Some(1), vals.next());
+    assert!(SteadyTime::now() - start < Duration::milliseconds(40));
+}
+
+#[test]
+pub fn test_stream_throttle_failure() {
+    let (tx, rx) = Stream::<i32, &'static str>::pair();
+    let vals = rx.throttle(Duration::milliseconds(10)).collect();
+
+    tx.send(1)
+        .and_then(|tx| tx.fail("nope"))
+        .fire();
+
+    assert_eq!(Err(AsyncError::Failed("nope")), vals.await());
+}
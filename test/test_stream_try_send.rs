@@ -0,0 +1,56 @@
+use eventual::*;
+
+#[test]
+pub fn test_stream_try_send_consumer_not_ready() {
+    let (tx, rx) = Stream::<i32, ()>::pair();
+    let busy = tx.send(1);
+
+    let busy = match busy.try_send(2) {
+        Err((val, busy)) => {
+            assert_eq!(2, val);
+            busy
+        }
+        Ok(_) => panic!("consumer is not ready"),
+    };
+
+    drop(busy);
+
+    let vals: Vec<i32> = rx.iter().collect();
+    assert_eq!(&[1], &vals[..]);
+}
+
+#[test]
+pub fn test_stream_try_send_consumer_ready() {
+    let (tx, rx) = Stream::<i32, ()>::pair();
+    let busy = tx.send(1);
+
+    // Consume the first value and request the next one
+    let (v, rest) = rx.await().unwrap().unwrap();
+    assert_eq!(1, v);
+
+    let (c, next) = Future::<Option<i32>, ()>::pair();
+    rest.receive(move |head| c.complete(head.unwrap().map(|(v, _)| v)));
+
+    match busy.try_send(2) {
+        Ok(busy) => drop(busy),
+        Err(_) => panic!("consumer is ready"),
+    }
+
+    assert_eq!(Ok(Some(2)), next.await());
+}
+
+#[test]
+pub fn test_stream_try_send_consumer_dropped() {
+    let (tx, rx) = Stream::<i32, ()>::pair();
+    let busy = tx.send(1);
+
+    drop(rx);
+
+    match busy.try_send(2) {
+        Err((val, busy)) => {
+            assert_eq!(2, val);
+            assert!(busy.await().is_err());
+        }
+        Ok(_) => panic!("consumer is gone"),
+    }
+}
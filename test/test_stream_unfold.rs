@@ -0,0 +1,34 @@
+use eventual::*;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[test]
+pub fn test_stream_unfold() {
+    let stream = Stream::<u32, ()>::unfold(0, |n| {
+        if n < 4 { Some((n * 10, n + 1)) } else { None }
+    });
+
+    assert_eq!(Ok(vec![0, 10, 20, 30]), stream.collect().await());
+}
+
+#[test]
+pub fn test_stream_unfold_empty() {
+    let stream = Stream::<u32, ()>::unfold((), |()| None);
+    assert_eq!(Ok(vec![]), stream.collect().await());
+}
+
+#[test]
+pub fn test_stream_unfold_is_lazy() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let c = calls.clone();
+
+    let stream = Stream::<u64, ()>::unfold(1, move |n| {
+        c.fetch_add(1, Ordering::SeqCst);
+        Some((n, n * 2))
+    });
+
+    assert_eq!(0, calls.load(Ordering::SeqCst));
+
+    assert_eq!(Ok(vec![1, 2, 4, 8]), stream.take(4).collect().await());
+    assert!(calls.load(Ordering::SeqCst) <= 5);
+}
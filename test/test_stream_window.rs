@@ -0,0 +1,39 @@
+use eventual::*;
+use super::nums;
+
+#[test]
+pub fn test_stream_window() {
+    let windows: Vec<Vec<usize>> = nums::<()>(0, 5).window(3).iter().collect();
+    assert_eq!(vec![vec![0, 1, 2], vec![1, 2, 3], vec![2, 3, 4]], windows);
+}
+
+#[test]
+pub fn test_stream_window_of_one() {
+    let windows: Vec<Vec<usize>> = nums::<()>(0, 3).window(1).iter().collect();
+    assert_eq!(vec![vec![0], vec![1], vec![2]], windows);
+}
+
+#[test]
+pub fn test_stream_window_shorter_than_size() {
+    let windows: Vec<Vec<usize>> = nums::<()>(0, 2).window(3).iter().collect();
+    assert!(windows.is_empty());
+}
+
+#[test]
+pub fn test_stream_window_failure() {
+    let (tx, rx) = Stream::<i32, &'static str>::pair();
+    let windows = rx.window(2).collect();
+
+    tx.send(1)
+        .and_then(|tx| tx.send(2))
+        .and_then(|tx| tx.fail("nope"))
+        .fire();
+
+    assert_eq!(Err(AsyncError::Failed("nope")), windows.await());
+}
+
+#[test]
+#[should_panic]
+pub fn test_stream_window_zero_size() {
+    let _ = nums::<()>(0, 5).window(0);
+}
@@ -0,0 +1,70 @@
+use eventual::*;
+use super::nums;
+use std::sync::mpsc::channel;
+
+#[test]
+pub fn test_stream_zip() {
+    let s = nums::<()>(0, 3).zip(nums(10, 13));
+
+    let vals: Vec<(usize, usize)> = s.iter().collect();
+    assert_eq!([(0, 10), (1, 11), (2, 12)], &vals[..]);
+}
+
+#[test]
+pub fn test_stream_zip_uneven_lengths() {
+    let vals: Vec<(usize, usize)> = nums::<()>(0, 2).zip(nums(10, 15)).iter().collect();
+    assert_eq!([(0, 10), (1, 11)], &vals[..]);
+
+    let vals: Vec<(usize, usize)> = nums::<()>(0, 5).zip(nums(10, 12)).iter().collect();
+    assert_eq!([(0, 10), (1, 11)], &vals[..]);
+}
+
+#[test]
+pub fn test_stream_zip_waits_for_both() {
+    let (tx1, rx1) = Stream::<i32, ()>::pair();
+    let (tx2, rx2) = Stream::<&'static str, ()>::pair();
+    let (tx, rx) = channel();
+
+    rx1.zip(rx2).each(move |v| tx.send(v).unwrap()).fire();
+
+    // The next value of either stream is not requested until the pair has
+    // been yielded
+    let busy1 = tx1.send(1);
+    assert!(rx.try_recv().is_err());
+    assert!(!busy1.is_ready());
+
+    let busy2 = tx2.send("one");
+    assert_eq!((1, "one"), rx.recv().unwrap());
+
+    // Both streams are asked for their next value
+    let tx1 = busy1.await().unwrap();
+    let tx2 = busy2.await().unwrap();
+
+    drop((tx1, tx2));
+}
+
+#[test]
+pub fn test_stream_zip_ends_immediately() {
+    let (tx1, rx1) = Stream::<i32, ()>::pair();
+    let (tx2, rx2) = Stream::<i32, ()>::pair();
+
+    let zipped = rx1.zip(rx2);
+
+    // The first stream ends while the second never produces a value
+    drop(tx1);
+
+    assert_eq!(Ok(vec![]), zipped.collect().await());
+    assert!(tx2.is_err());
+}
+
+#[test]
+pub fn test_stream_zip_fails_immediately() {
+    let (tx1, rx1) = Stream::<i32, &'static str>::pair();
+    let (tx2, rx2) = Stream::<i32, &'static str>::pair();
+
+    let zipped = rx1.zip(rx2);
+    tx2.fail("nope");
+
+    assert_eq!(Err(AsyncError::Failed("nope")), zipped.collect().await());
+    assert!(tx1.is_err());
+}
@@ -0,0 +1,80 @@
+use eventual::*;
+use std::sync::mpsc::channel;
+use super::{spawn, sleep_ms};
+
+#[test]
+pub fn test_async_mutex_lock() {
+    let mutex = AsyncMutex::new(vec![1]);
+
+    {
+        let mut guard = mutex.lock().await().unwrap();
+        guard.push(2);
+    }
+
+    assert_eq!(vec![1, 2], *mutex.lock().await().unwrap());
+}
+
+#[test]
+pub fn test_async_mutex_grants_in_order() {
+    let mutex = AsyncMutex::new(Vec::new());
+    let guard = mutex.lock().await().unwrap();
+    let (tx, rx) = channel();
+
+    for i in 0..3 {
+        let tx = tx.clone();
+
+        mutex.lock().receive(move |res| {
+            let mut guard = res.unwrap();
+            guard.push(i);
+            tx.send(()).unwrap();
+        });
+    }
+
+    assert!(rx.try_recv().is_err());
+    drop(guard);
+
+    for _ in 0..3 {
+        rx.recv().unwrap();
+    }
+
+    assert_eq!(vec![0, 1, 2], *mutex.lock().await().unwrap());
+}
+
+#[test]
+pub fn test_async_mutex_exclusive_across_threads() {
+    let mutex = AsyncMutex::new(0);
+    let (tx, rx) = channel();
+
+    for _ in 0..4 {
+        let mutex = mutex.clone();
+        let tx = tx.clone();
+
+        spawn(move || {
+            for _ in 0..10 {
+                let mut guard = mutex.lock().await().unwrap();
+                let val = *guard;
+                sleep_ms(1);
+                *guard = val + 1;
+            }
+
+            tx.send(()).unwrap();
+        });
+    }
+
+    for _ in 0..4 {
+        rx.recv().unwrap();
+    }
+
+    assert_eq!(40, *mutex.lock().await().unwrap());
+}
+
+#[test]
+pub fn test_async_mutex_try_lock() {
+    let mutex = AsyncMutex::new(1);
+
+    let guard = mutex.try_lock().unwrap();
+    assert!(mutex.try_lock().is_none());
+
+    drop(guard);
+    assert_eq!(1, *mutex.try_lock().unwrap());
+}
@@ -1,4 +1,5 @@
-use eventual::{Async, Timer};
+use super::sleep_ms;
+use eventual::{sleep, Async, AsyncError, Future, Timer, TimerError};
 use std::sync::mpsc::*;
 use std::thread;
 use time::{SteadyTime, Duration};
@@ -59,3 +60,142 @@ pub fn test_timer_interval() {
 fn ms(ms: u32) -> Duration {
     Duration::milliseconds(ms as i64)
 }
+
+#[test]
+pub fn test_timer_interval_duration() {
+    let timer = Timer::new();
+    let start = SteadyTime::now();
+
+    let ticks: Vec<()> = timer.interval(ms(50)).iter().take(5).collect();
+    assert_eq!(5, ticks.len());
+
+    let elapsed = SteadyTime::now() - start;
+    // The scheduler has millisecond resolution
+    assert!(elapsed >= ms(240), "actual={}", elapsed);
+}
+
+#[test]
+pub fn test_timer_interval_does_not_drift() {
+    let timer = Timer::new();
+    let start = SteadyTime::now();
+
+    // Consume the ticks slower than they are produced. The second tick is
+    // delivered as soon as it is requested (at ~350ms) and the third is due
+    // at 300ms, so is delivered right away rather than at ~450ms.
+    let mut ticks = timer.interval(ms(100)).iter();
+
+    ticks.next().unwrap();
+    sleep_ms(250);
+
+    ticks.next().unwrap();
+    ticks.next().unwrap();
+
+    let elapsed = SteadyTime::now() - start;
+    assert!(elapsed < ms(420), "actual={}", elapsed);
+}
+
+#[test]
+pub fn test_timer_interval_invalid_duration() {
+    let timer = Timer::new();
+
+    let res = timer.interval(ms(0)).collect().await();
+    assert_eq!(Err(AsyncError::Failed(TimerError::InvalidDuration)), res);
+}
+
+#[test]
+pub fn test_timer_delay_holds_result() {
+    let timer = Timer::new();
+    let start = SteadyTime::now();
+
+    let res = timer.delay(Future::<i32, ()>::of(123), ms(200)).await();
+
+    assert_eq!(Ok(123), res);
+    assert!(SteadyTime::now() - start >= ms(190));
+}
+
+#[test]
+pub fn test_timer_delay_starts_immediately() {
+    let timer = Timer::new();
+    let (c, f) = Future::<i32, ()>::pair();
+    let (tx, rx) = channel();
+
+    c.receive(move |res| {
+        if let Ok(c) = res {
+            tx.send("started").unwrap();
+            c.complete(123);
+        }
+    });
+
+    let delayed = timer.delay(f, ms(200));
+
+    // Interest in the original future is registered before the delayed
+    // future is consumed
+    assert_eq!("started", rx.try_recv().unwrap());
+    assert_eq!(Ok(123), delayed.await());
+}
+
+#[test]
+pub fn test_timer_delay_drop_cancels_async() {
+    let timer = Timer::new();
+    let (c, f) = Future::<i32, ()>::pair();
+
+    let delayed = timer.delay(f, ms(200));
+    assert!(!c.is_err());
+
+    drop(delayed);
+    assert!(c.is_err());
+}
+
+#[test]
+pub fn test_timer_delay_observes_slow_async() {
+    let timer = Timer::new();
+    let (c, f) = Future::<i32, ()>::pair();
+    let start = SteadyTime::now();
+
+    let delayed = timer.delay(f, ms(50));
+
+    thread::spawn(move || {
+        sleep_ms(200);
+        c.complete(123);
+    });
+
+    assert_eq!(Ok(123), delayed.await());
+    assert!(SteadyTime::now() - start >= ms(190));
+}
+
+#[test]
+pub fn test_timer_delay_holds_error() {
+    let timer = Timer::new();
+    let start = SteadyTime::now();
+
+    let res = timer.delay(Future::<i32, &'static str>::error("nope"), ms(200)).await();
+
+    assert_eq!(Err(AsyncError::Failed("nope")), res);
+    assert!(SteadyTime::now() - start >= ms(190));
+}
+
+#[test]
+pub fn test_timer_one_shot() {
+    let timer = Timer::new();
+    let start = SteadyTime::now();
+
+    assert_eq!(Ok(()), timer.one_shot(Duration::milliseconds(100)).await());
+    assert!(SteadyTime::now() - start >= Duration::milliseconds(90));
+}
+
+#[test]
+pub fn test_timer_one_shot_invalid_duration() {
+    let timer = Timer::new();
+    let res = timer.one_shot(Duration::milliseconds(-1)).await();
+
+    assert_eq!(Err(AsyncError::Failed(TimerError::InvalidDuration)), res);
+}
+
+#[test]
+pub fn test_timer_sleep() {
+    let start = SteadyTime::now();
+
+    assert_eq!(Ok(()), sleep(Duration::milliseconds(100)).await());
+    assert!(SteadyTime::now() - start >= Duration::milliseconds(90));
+    assert_eq!(Ok(()), sleep(Duration::zero()).await());
+}